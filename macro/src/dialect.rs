@@ -1,3 +1,4 @@
+mod enum_attribute;
 mod error;
 mod generation;
 mod input;
@@ -8,7 +9,7 @@ mod utility;
 
 use self::{
     error::Error,
-    generation::generate_operation,
+    generation::{generate_enum_attribute, generate_operation},
     utility::{sanitize_documentation, sanitize_snake_case_identifier},
 };
 pub use input::DialectInput;
@@ -16,7 +17,7 @@ use operation::Operation;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
-use std::{env, fmt::Display, path::Path, process::Command, str};
+use std::{collections::BTreeMap, env, fmt::Display, path::Path, process::Command, str};
 use tblgen::{record::Record, record_keeper::RecordKeeper, TableGenParser};
 
 const LLVM_MAJOR_VERSION: usize = 17;
@@ -67,8 +68,21 @@ fn generate_dialect_module(
         .all_derived_definitions("Op")
         .map(Operation::new)
         .collect::<Result<Vec<_>, _>>()?
-        .iter()
+        .into_iter()
         .filter(|operation| operation.dialect_name() == dialect_name)
+        .collect::<Vec<_>>();
+    // Enums can be shared by multiple operations.
+    let enums = operations
+        .iter()
+        .flat_map(|operation| operation.attributes())
+        .flat_map(|attribute| attribute.r#enum())
+        .map(|r#enum| (r#enum.identifier().to_string(), r#enum))
+        .collect::<BTreeMap<_, _>>()
+        .into_values()
+        .map(generate_enum_attribute)
+        .collect::<Vec<_>>();
+    let operations = operations
+        .iter()
         .map(generate_operation)
         .collect::<Vec<_>>();
 
@@ -81,6 +95,7 @@ fn generate_dialect_module(
     Ok(quote! {
         #[doc = #doc]
         pub mod #name {
+            #(#enums)*
             #(#operations)*
         }
     })
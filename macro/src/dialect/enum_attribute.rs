@@ -0,0 +1,119 @@
+use super::{
+    error::Error,
+    utility::{sanitize_documentation, sanitize_pascal_case_identifier},
+};
+use syn::Ident;
+use tblgen::{error::WithLocation, record::Record};
+
+const WRAPPER_CLASSES: &[&str] = &[
+    "OptionalAttr",
+    "DefaultValuedAttr",
+    "DefaultValuedOptionalAttr",
+    "ConfinedAttr",
+];
+const DEFAULT_ASSEMBLY_FORMAT: &str = "`<` $value `>`";
+
+#[derive(Debug)]
+pub struct EnumAttribute {
+    identifier: Ident,
+    summary: String,
+    cases: Vec<EnumCase>,
+}
+
+#[derive(Debug)]
+pub struct EnumCase {
+    identifier: Ident,
+    attribute: String,
+}
+
+impl EnumAttribute {
+    pub fn new(mut record: Record) -> Result<Option<Self>, Error> {
+        while WRAPPER_CLASSES
+            .iter()
+            .any(|class| record.subclass_of(class))
+        {
+            record = record.def_value("baseAttr")?;
+        }
+
+        if record.subclass_of("EnumAttr") {
+            let information = record.def_value("enum")?;
+
+            // Only enum attributes printed in their default format can be
+            // converted from and into strings reliably.
+            if information.subclass_of("BitEnumAttr")
+                || record.string_value("assemblyFormat").ok().as_deref()
+                    != Some(DEFAULT_ASSEMBLY_FORMAT)
+            {
+                return Ok(None);
+            }
+
+            let prefix = format!(
+                "#{}.{}",
+                record.def_value("dialect")?.str_value("name")?,
+                record.str_value("mnemonic")?
+            );
+
+            Self::build(information, |case| {
+                Ok(format!("{prefix}<{}>", case.str_value("str")?))
+            })
+            .map(Some)
+        } else if record.subclass_of("IntEnumAttr") && !record.subclass_of("BitEnumAttr") {
+            let underlying_type = record.str_value("underlyingType")?;
+            let bit_width = underlying_type
+                .trim_start_matches("uint")
+                .trim_end_matches("_t");
+
+            Self::build(record, |case| {
+                Ok(format!("{} : i{bit_width}", case.int_value("value")?))
+            })
+            .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn build(
+        information: Record,
+        format_attribute: impl Fn(Record) -> Result<String, Error>,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            identifier: sanitize_pascal_case_identifier(information.str_value("className")?)?,
+            summary: sanitize_documentation(information.str_value("summary")?)?,
+            cases: information
+                .list_value("enumerants")?
+                .iter()
+                .map(|case| {
+                    let case =
+                        Record::try_from(case).map_err(|error| error.set_location(information))?;
+
+                    Ok(EnumCase {
+                        identifier: sanitize_pascal_case_identifier(case.str_value("symbol")?)?,
+                        attribute: format_attribute(case)?,
+                    })
+                })
+                .collect::<Result<_, Error>>()?,
+        })
+    }
+
+    pub fn identifier(&self) -> &Ident {
+        &self.identifier
+    }
+
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    pub fn cases(&self) -> impl Iterator<Item = &EnumCase> {
+        self.cases.iter()
+    }
+}
+
+impl EnumCase {
+    pub fn identifier(&self) -> &Ident {
+        &self.identifier
+    }
+
+    pub fn attribute(&self) -> &str {
+        &self.attribute
+    }
+}
@@ -1,16 +1,19 @@
 mod attribute_accessor;
 mod element_accessor;
+mod enum_attribute;
 mod operand_accessor;
 mod operation_builder;
 mod region_accessor;
 mod result_accessor;
 mod successor_accessor;
 
+pub use self::enum_attribute::generate_enum_attribute;
 use self::{
     attribute_accessor::generate_attribute_accessors,
     operand_accessor::generate_operand_accessor,
     operation_builder::{
-        generate_default_constructor, generate_operation_builder, generate_operation_builder_fn,
+        generate_count_checks, generate_default_constructor, generate_operation_builder,
+        generate_operation_builder_fn,
     },
     region_accessor::generate_region_accessor,
     result_accessor::generate_result_accessor,
@@ -55,6 +58,7 @@ pub fn generate_operation(operation: &Operation) -> TokenStream {
     let builder_tokens = generate_operation_builder(&builder);
     let builder_fn = generate_operation_builder_fn(&builder);
     let default_constructor = generate_default_constructor(&builder);
+    let count_checks = generate_count_checks(&builder);
 
     quote! {
         #[doc = #summary]
@@ -75,6 +79,37 @@ pub fn generate_operation(operation: &Operation) -> TokenStream {
                 &self.operation
             }
 
+            /// Creates an operation from a generic operation without checking its
+            /// name.
+            ///
+            /// The name must be checked by a caller beforehand. Otherwise,
+            /// accessors might return errors or values of unexpected fields.
+            pub fn from_operation_unchecked(
+                operation: ::melior::ir::operation::Operation<'c>,
+            ) -> Self {
+                Self { operation }
+            }
+
+            /// Returns all operands.
+            ///
+            /// Unlike the accessors of individual operands, it iterates over
+            /// operands of all operand segments in order.
+            pub fn all_operands(
+                &self,
+            ) -> impl ExactSizeIterator<Item = ::melior::ir::Value<'c, '_>> {
+                self.operation.operands()
+            }
+
+            /// Returns all results.
+            ///
+            /// Unlike the accessors of individual results, it iterates over
+            /// results of all result segments in order.
+            pub fn all_results(
+                &self,
+            ) -> impl ExactSizeIterator<Item = ::melior::ir::operation::OperationResult<'c, '_>> {
+                self.operation.results()
+            }
+
             #builder_fn
 
             #(#result_accessors)*
@@ -94,8 +129,29 @@ pub fn generate_operation(operation: &Operation) -> TokenStream {
             fn try_from(
                 operation: ::melior::ir::operation::Operation<'c>,
                 ) -> Result<Self, Self::Error> {
-                // TODO Check an operation name.
-                Ok(Self { operation })
+                let name = operation.name();
+                let name = name.as_string_ref().as_str()?;
+
+                if name == #operation_name {
+                    #count_checks
+
+                    Ok(Self { operation })
+                } else {
+                    Err(::melior::Error::OperationExpected {
+                        expected: #operation_name,
+                        actual: name.into(),
+                    })
+                }
+            }
+        }
+
+        impl<'c> ::melior::ir::operation::OperationWrapper<'c> for #identifier<'c> {
+            fn name() -> &'static str {
+                #operation_name
+            }
+
+            fn from_operation_unchecked(operation: ::melior::ir::operation::Operation<'c>) -> Self {
+                Self { operation }
             }
         }
 
@@ -104,5 +160,11 @@ pub fn generate_operation(operation: &Operation) -> TokenStream {
                 operation.operation
             }
         }
+
+        impl<'c> AsRef<::melior::ir::operation::Operation<'c>> for #identifier<'c> {
+            fn as_ref(&self) -> &::melior::ir::operation::Operation<'c> {
+                &self.operation
+            }
+        }
     }
 }
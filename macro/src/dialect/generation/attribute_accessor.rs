@@ -20,7 +20,16 @@ fn generate_getter(attribute: &Attribute) -> TokenStream {
     let identifier = attribute.singular_identifier();
     let return_type = attribute.return_type();
     let body = if attribute.is_unit() {
-        quote! { self.operation.attribute(#name).is_some() }
+        quote! { self.operation.attribute(#name).is_ok() }
+    } else if attribute.is_optional() {
+        // Optional attributes are absent if they are not set on operations.
+        quote! {
+            if self.operation.has_attribute(#name) {
+                Ok(Some(self.operation.attribute(#name)?.try_into()?))
+            } else {
+                Ok(None)
+            }
+        }
     } else {
         quote! { Ok(self.operation.attribute(#name)?.try_into()?) }
     };
@@ -36,26 +45,34 @@ fn generate_getter(attribute: &Attribute) -> TokenStream {
 fn generate_setter(attribute: &Attribute) -> TokenStream {
     let name = attribute.name();
 
-    let body = if attribute.is_unit() {
+    let identifier = attribute.set_identifier();
+    let r#type = attribute.parameter_type();
+
+    // Enum values and unit attributes need a context with a correct lifetime to
+    // be converted into attributes.
+    if attribute.is_unit() {
         quote! {
-            if value {
-                self.operation.set_attribute(#name, Attribute::unit(self.operation.context()));
-            } else {
-                self.operation.remove_attribute(#name)
+            pub fn #identifier(&mut self, context: &'c ::melior::Context, value: #r#type) {
+                if value {
+                    self.operation
+                        .set_attribute(#name, ::melior::ir::Attribute::unit(context));
+                } else {
+                    // Unit attributes are absent if they are unset.
+                    let _ = self.operation.remove_attribute(#name);
+                }
             }
         }
-    } else {
+    } else if attribute.r#enum().is_some() {
         quote! {
-            self.operation.set_attribute(#name, value.into());
+            pub fn #identifier(&mut self, context: &'c ::melior::Context, value: #r#type) {
+                self.operation.set_attribute(#name, value.attribute(context));
+            }
         }
-    };
-
-    let identifier = attribute.set_identifier();
-    let r#type = attribute.parameter_type();
-
-    quote! {
-        pub fn #identifier(&mut self, value: #r#type) {
-            #body
+    } else {
+        quote! {
+            pub fn #identifier(&mut self, value: #r#type) {
+                self.operation.set_attribute(#name, value.into());
+            }
         }
     }
 }
@@ -0,0 +1,54 @@
+use crate::dialect::enum_attribute::EnumAttribute;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn generate_enum_attribute(r#enum: &EnumAttribute) -> TokenStream {
+    let identifier = r#enum.identifier();
+    let name = identifier.to_string();
+    let summary = r#enum.summary();
+    let variants = r#enum
+        .cases()
+        .map(|case| case.identifier())
+        .collect::<Vec<_>>();
+    let attributes = r#enum
+        .cases()
+        .map(|case| case.attribute())
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[doc = #summary]
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub enum #identifier {
+            #(#variants),*
+        }
+
+        impl #identifier {
+            /// Converts an enum value into an attribute.
+            pub fn attribute<'c>(self, context: &'c ::melior::Context) -> ::melior::ir::Attribute<'c> {
+                ::melior::ir::Attribute::parse(
+                    context,
+                    match self {
+                        #(Self::#variants => #attributes),*
+                    },
+                )
+                .expect("valid enum attribute")
+            }
+        }
+
+        impl<'c> TryFrom<::melior::ir::Attribute<'c>> for #identifier {
+            type Error = ::melior::Error;
+
+            fn try_from(attribute: ::melior::ir::Attribute<'c>) -> Result<Self, Self::Error> {
+                let context = ::melior::ir::attribute::AttributeLike::context(&attribute);
+
+                #(
+                    if ::melior::ir::Attribute::parse(&context, #attributes) == Some(attribute) {
+                        return Ok(Self::#variants);
+                    }
+                )*
+
+                Err(::melior::Error::AttributeExpected(#name, attribute.to_string()))
+            }
+        }
+    }
+}
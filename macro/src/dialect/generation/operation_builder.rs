@@ -1,6 +1,10 @@
-use crate::dialect::operation::{OperationBuilder, OperationField};
-use proc_macro2::TokenStream;
+use crate::dialect::operation::{
+    Attribute, Operand, OperationBuilder, OperationElement, OperationField, OperationResult,
+    Region, Successor,
+};
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
+use syn::{parse_quote, Type};
 
 pub fn generate_operation_builder(builder: &OperationBuilder) -> TokenStream {
     let result_fns = if builder.operation().can_infer_type() {
@@ -9,28 +13,30 @@ pub fn generate_operation_builder(builder: &OperationBuilder) -> TokenStream {
         builder
             .operation()
             .results()
-            .map(|result| generate_field_fn(builder, result))
+            .enumerate()
+            .map(|(index, result)| generate_result_fn(builder, result, index))
             .collect::<Vec<_>>()
     };
     let operand_fns = builder
         .operation()
         .operands()
-        .map(|operand| generate_field_fn(builder, operand))
+        .enumerate()
+        .map(|(index, operand)| generate_operand_fn(builder, operand, index))
         .collect::<Vec<_>>();
     let region_fns = builder
         .operation()
         .regions()
-        .map(|region| generate_field_fn(builder, region))
+        .map(|region| generate_region_fn(builder, region))
         .collect::<Vec<_>>();
     let successor_fns = builder
         .operation()
         .successors()
-        .map(|successor| generate_field_fn(builder, successor))
+        .map(|successor| generate_successor_fn(builder, successor))
         .collect::<Vec<_>>();
     let attribute_fns = builder
         .operation()
         .attributes()
-        .map(|attribute| generate_field_fn(builder, attribute))
+        .map(|attribute| generate_attribute_fn(builder, attribute))
         .collect::<Vec<_>>();
 
     let new_fn = generate_new_fn(builder);
@@ -41,14 +47,17 @@ pub fn generate_operation_builder(builder: &OperationBuilder) -> TokenStream {
         "A builder for {}.",
         builder.operation().documentation_name()
     );
+    let lifetimes = generate_lifetimes(builder);
     let type_arguments = builder.type_state().parameters();
     let state_types = builder.type_state().parameters();
+    let segment_fields = generate_segment_fields(builder);
 
     quote! {
         #[doc = #doc]
-        pub struct #identifier<'c, #(#type_arguments),*> {
+        pub struct #identifier<#lifetimes, #(#type_arguments),*> {
             builder: ::melior::ir::operation::OperationBuilder<'c>,
             context: &'c ::melior::Context,
+            #segment_fields
             _state: ::std::marker::PhantomData<(#(#state_types),*)>,
         }
 
@@ -64,26 +73,133 @@ pub fn generate_operation_builder(builder: &OperationBuilder) -> TokenStream {
     }
 }
 
-// TODO Split this function for different kinds of fields.
-fn generate_field_fn(builder: &OperationBuilder, field: &impl OperationField) -> TokenStream {
+fn generate_result_fn(
+    builder: &OperationBuilder,
+    result: &OperationResult,
+    index: usize,
+) -> TokenStream {
+    let identifier = result.singular_identifier();
+    let add_arguments = result.add_arguments(identifier);
+
+    generate_field_fn(
+        builder,
+        result,
+        result.parameter_type(),
+        if has_result_segments(builder) {
+            quote! {
+                self.result_segments[#index] = (#add_arguments).to_vec();
+            }
+        } else {
+            quote! {
+                self.builder = self.builder.add_results(#add_arguments);
+            }
+        },
+    )
+}
+
+fn generate_operand_fn(builder: &OperationBuilder, operand: &Operand, index: usize) -> TokenStream {
+    let identifier = operand.singular_identifier();
+    let add_arguments = operand.add_arguments(identifier);
+
+    if builder.operation().has_attribute_sized_operands() {
+        let r#type: Type = parse_quote!(::melior::ir::Value<'c, 'a>);
+
+        generate_field_fn(
+            builder,
+            operand,
+            if operand.is_variadic() {
+                parse_quote!(&[#r#type])
+            } else {
+                r#type
+            },
+            // Operands are added at once on build as their order matters while
+            // operand functions can be called in any order.
+            quote! {
+                self.operand_segments[#index] = (#add_arguments).to_vec();
+            },
+        )
+    } else {
+        generate_field_fn(
+            builder,
+            operand,
+            operand.parameter_type(),
+            quote! {
+                self.builder = self.builder.add_operands(#add_arguments);
+            },
+        )
+    }
+}
+
+fn generate_region_fn(builder: &OperationBuilder, region: &Region) -> TokenStream {
+    let identifier = region.singular_identifier();
+    let add_identifier = format_ident!("add_{}", region.plural_kind_identifier());
+    let add_arguments = region.add_arguments(identifier);
+
+    generate_field_fn(
+        builder,
+        region,
+        region.parameter_type(),
+        quote! {
+            self.builder = self.builder.#add_identifier(#add_arguments);
+        },
+    )
+}
+
+fn generate_successor_fn(builder: &OperationBuilder, successor: &Successor) -> TokenStream {
+    let identifier = successor.singular_identifier();
+    let add_arguments = successor.add_arguments(identifier);
+
+    generate_field_fn(
+        builder,
+        successor,
+        successor.parameter_type(),
+        quote! {
+            self.builder = self.builder.add_successors(#add_arguments);
+        },
+    )
+}
+
+fn generate_attribute_fn(builder: &OperationBuilder, attribute: &Attribute) -> TokenStream {
+    let identifier = attribute.singular_identifier();
+    let add_arguments = attribute.add_arguments(identifier);
+
+    generate_field_fn(
+        builder,
+        attribute,
+        attribute.parameter_type(),
+        if attribute.is_unit() {
+            // Unit attributes are added only if they are set.
+            quote! {
+                if #identifier {
+                    self.builder = self.builder.add_attributes(#add_arguments);
+                }
+            }
+        } else {
+            quote! {
+                self.builder = self.builder.add_attributes(#add_arguments);
+            }
+        },
+    )
+}
+
+fn generate_field_fn(
+    builder: &OperationBuilder,
+    field: &impl OperationField,
+    parameter_type: Type,
+    update: TokenStream,
+) -> TokenStream {
     let builder_identifier = builder.identifier();
     let identifier = field.singular_identifier();
-    let parameter_type = field.parameter_type();
     let argument = quote! { #identifier: #parameter_type };
-    let add_identifier = format_ident!("add_{}", field.plural_kind_identifier());
-
-    // Argument types can be singular and variadic. But `add` functions in Melior
-    // are always variadic, so we need to create a slice or `Vec` for singular
-    // arguments.
-    let add_arguments = field.add_arguments(identifier);
+    let lifetimes = generate_lifetimes(builder);
 
     if field.is_optional() {
         let parameters = builder.type_state().parameters().collect::<Vec<_>>();
 
         quote! {
-            impl<'c, #(#parameters),*> #builder_identifier<'c, #(#parameters),*> {
-                pub fn #identifier(mut self, #argument) -> #builder_identifier<'c, #(#parameters),*> {
-                    self.builder = self.builder.#add_identifier(#add_arguments);
+            impl<#lifetimes, #(#parameters),*> #builder_identifier<#lifetimes, #(#parameters),*> {
+                pub fn #identifier(mut self, #argument) -> #builder_identifier<#lifetimes, #(#parameters),*> {
+                    #update
                     self
                 }
             }
@@ -92,13 +208,17 @@ fn generate_field_fn(builder: &OperationBuilder, field: &impl OperationField) ->
         let parameters = builder.type_state().parameters_without(field.name());
         let arguments_set = builder.type_state().arguments_set(field.name(), true);
         let arguments_unset = builder.type_state().arguments_set(field.name(), false);
+        let segment_moves = generate_segment_moves(builder);
 
         quote! {
-            impl<'c, #(#parameters),*> #builder_identifier<'c, #(#arguments_unset),*> {
-                pub fn #identifier(self, #argument) -> #builder_identifier<'c, #(#arguments_set),*> {
+            impl<#lifetimes, #(#parameters),*> #builder_identifier<#lifetimes, #(#arguments_unset),*> {
+                pub fn #identifier(mut self, #argument) -> #builder_identifier<#lifetimes, #(#arguments_set),*> {
+                    #update
+
                     #builder_identifier {
                         context: self.context,
-                        builder: self.builder.#add_identifier(#add_arguments),
+                        builder: self.builder,
+                        #segment_moves
                         _state: Default::default(),
                     }
                 }
@@ -107,6 +227,110 @@ fn generate_field_fn(builder: &OperationBuilder, field: &impl OperationField) ->
     }
 }
 
+fn generate_lifetimes(builder: &OperationBuilder) -> TokenStream {
+    // Builders of operations with attribute-sized operands keep operands until
+    // build and so borrow their parents.
+    if builder.operation().has_attribute_sized_operands() {
+        quote! { 'c, 'a }
+    } else {
+        quote! { 'c }
+    }
+}
+
+fn has_result_segments(builder: &OperationBuilder) -> bool {
+    builder.operation().has_attribute_sized_results() && !builder.operation().can_infer_type()
+}
+
+fn generate_segment_fields(builder: &OperationBuilder) -> TokenStream {
+    let operation = builder.operation();
+    let operand_len = operation.operand_len();
+    let result_len = operation.result_len();
+
+    let operand_field = operation.has_attribute_sized_operands().then(|| {
+        quote! { operand_segments: [::std::vec::Vec<::melior::ir::Value<'c, 'a>>; #operand_len], }
+    });
+    let result_field = has_result_segments(builder).then(|| {
+        quote! { result_segments: [::std::vec::Vec<::melior::ir::Type<'c>>; #result_len], }
+    });
+
+    quote! {
+        #operand_field
+        #result_field
+    }
+}
+
+fn generate_segment_moves(builder: &OperationBuilder) -> TokenStream {
+    let operand_move = builder
+        .operation()
+        .has_attribute_sized_operands()
+        .then(|| quote! { operand_segments: self.operand_segments, });
+    let result_move =
+        has_result_segments(builder).then(|| quote! { result_segments: self.result_segments, });
+
+    quote! {
+        #operand_move
+        #result_move
+    }
+}
+
+fn generate_segment_defaults(builder: &OperationBuilder) -> TokenStream {
+    let operand_default = builder
+        .operation()
+        .has_attribute_sized_operands()
+        .then(|| quote! { operand_segments: Default::default(), });
+    let result_default =
+        has_result_segments(builder).then(|| quote! { result_segments: Default::default(), });
+
+    quote! {
+        #operand_default
+        #result_default
+    }
+}
+
+fn generate_segment_additions(builder: &OperationBuilder) -> TokenStream {
+    let operand_addition = builder.operation().has_attribute_sized_operands().then(|| {
+        generate_segment_addition(
+            &format_ident!("operand_segments"),
+            &format_ident!("add_operands"),
+            "operand_segment_sizes",
+        )
+    });
+    let result_addition = has_result_segments(builder).then(|| {
+        generate_segment_addition(
+            &format_ident!("result_segments"),
+            &format_ident!("add_results"),
+            "result_segment_sizes",
+        )
+    });
+
+    quote! {
+        #result_addition
+        #operand_addition
+    }
+}
+
+fn generate_segment_addition(
+    field: &Ident,
+    add_identifier: &Ident,
+    attribute_name: &str,
+) -> TokenStream {
+    quote! {
+        .#add_identifier(&self.#field.concat())
+        .add_attributes(&[(
+            ::melior::ir::Identifier::new(self.context, #attribute_name),
+            ::melior::ir::attribute::DenseI32ArrayAttribute::new(
+                self.context,
+                &self
+                    .#field
+                    .iter()
+                    .map(|segment| segment.len() as i32)
+                    .collect::<Vec<_>>(),
+            )
+            .into(),
+        )])
+    }
+}
+
 fn generate_build_fn(builder: &OperationBuilder) -> TokenStream {
     let identifier = builder.identifier();
     let arguments = builder.type_state().arguments_all_set(true);
@@ -116,27 +340,172 @@ fn generate_build_fn(builder: &OperationBuilder) -> TokenStream {
         .operation()
         .can_infer_type()
         .then_some(quote! { .enable_result_type_inference() });
+    let segment_additions = generate_segment_additions(builder);
+    let lifetimes = generate_lifetimes(builder);
 
     quote! {
-        impl<'c> #identifier<'c, #(#arguments),*> {
+        impl<#lifetimes> #identifier<#lifetimes, #(#arguments),*> {
+            /// Builds and verifies an operation.
+            pub fn try_build(self) -> Result<#operation_identifier<'c>, ::melior::Error> {
+                let operation = self.build_unverified()?;
+
+                operation.as_operation().try_verify()?;
+
+                Ok(operation)
+            }
+
+            /// Builds an operation.
+            ///
+            /// Note that operations are not verified here because some of them
+            /// are valid only after being inserted into their parents. Use
+            /// `try_build` to verify standalone ones.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the operation cannot be built.
             pub fn build(self) -> #operation_identifier<'c> {
-                self.builder #maybe_infer.build().expect("valid operation").try_into().expect(#error)
+                self.build_unverified().expect(#error)
+            }
+
+            fn build_unverified(self) -> Result<#operation_identifier<'c>, ::melior::Error> {
+                self.builder #segment_additions #maybe_infer.build()?.try_into()
             }
         }
     }
 }
 
+pub fn generate_count_checks(builder: &OperationBuilder) -> TokenStream {
+    let operation = builder.operation();
+    let name = operation.full_operation_name();
+    let operand_check = generate_count_check(
+        &format!("operands of {name}"),
+        quote! { operation.operand_count() },
+        operation
+            .operands()
+            .map(|operand| operand as &dyn OperationElement),
+    );
+    let result_check = generate_count_check(
+        &format!("results of {name}"),
+        quote! { operation.result_count() },
+        operation
+            .results()
+            .map(|result| result as &dyn OperationElement),
+    );
+    let operand_segment_check = operation.has_attribute_sized_operands().then(|| {
+        generate_segment_check(
+            &format!("operand segments of {name}"),
+            "operand_segment_sizes",
+            quote! { operation.operand_count() },
+        )
+    });
+    let result_segment_check = has_result_segments(builder).then(|| {
+        generate_segment_check(
+            &format!("result segments of {name}"),
+            "result_segment_sizes",
+            quote! { operation.result_count() },
+        )
+    });
+
+    quote! {
+        #operand_check
+        #result_check
+        #operand_segment_check
+        #result_segment_check
+    }
+}
+
+fn generate_count_check<'a>(
+    r#type: &str,
+    count: TokenStream,
+    elements: impl Iterator<Item = &'a dyn OperationElement>,
+) -> TokenStream {
+    let mut minimum = 0;
+    let mut maximum = Some(0);
+
+    for element in elements {
+        if element.is_variadic() {
+            maximum = None;
+        } else if element.is_optional() {
+            maximum = maximum.map(|count| count + 1);
+        } else {
+            minimum += 1;
+            maximum = maximum.map(|count| count + 1);
+        }
+    }
+
+    if maximum == Some(minimum) {
+        quote! {
+            if #count != #minimum {
+                return Err(::melior::Error::ElementCountMismatch {
+                    r#type: #r#type.into(),
+                    expected: #minimum,
+                    actual: #count,
+                });
+            }
+        }
+    } else {
+        let conditions = (minimum > 0)
+            .then(|| quote! { #count < #minimum })
+            .into_iter()
+            .chain(maximum.map(|maximum| quote! { #count > #maximum }))
+            .collect::<Vec<_>>();
+
+        if conditions.is_empty() {
+            return Default::default();
+        }
+
+        let maximum = if let Some(maximum) = maximum {
+            quote! { Some(#maximum) }
+        } else {
+            quote! { None }
+        };
+
+        quote! {
+            if #(#conditions)||* {
+                return Err(::melior::Error::ElementCountOutOfRange {
+                    r#type: #r#type.into(),
+                    minimum: #minimum,
+                    maximum: #maximum,
+                    actual: #count,
+                });
+            }
+        }
+    }
+}
+
+fn generate_segment_check(r#type: &str, attribute_name: &str, count: TokenStream) -> TokenStream {
+    quote! {
+        let sizes = ::melior::ir::attribute::DenseI32ArrayAttribute::try_from(
+            operation.attribute(#attribute_name)?,
+        )?;
+        let size = (0..sizes.len())
+            .map(|index| sizes.element(index))
+            .sum::<Result<i32, _>>()? as usize;
+
+        if size != #count {
+            return Err(::melior::Error::ElementCountMismatch {
+                r#type: #r#type.into(),
+                expected: size,
+                actual: #count,
+            });
+        }
+    }
+}
+
 fn generate_new_fn(builder: &OperationBuilder) -> TokenStream {
     let identifier = builder.identifier();
     let name = &builder.operation().full_operation_name();
     let arguments = builder.type_state().arguments_all_set(false);
+    let segment_defaults = generate_segment_defaults(builder);
+    let lifetimes = generate_lifetimes(builder);
 
     quote! {
-        impl<'c> #identifier<'c, #(#arguments),*> {
+        impl<#lifetimes> #identifier<#lifetimes, #(#arguments),*> {
             pub fn new(context: &'c ::melior::Context, location: ::melior::ir::Location<'c>) -> Self {
                 Self {
                     context,
                     builder: ::melior::ir::operation::OperationBuilder::new(#name, location),
+                    #segment_defaults
                     _state: Default::default(),
                 }
             }
@@ -147,13 +516,18 @@ fn generate_new_fn(builder: &OperationBuilder) -> TokenStream {
 pub fn generate_operation_builder_fn(builder: &OperationBuilder) -> TokenStream {
     let builder_ident = builder.identifier();
     let arguments = builder.type_state().arguments_all_set(false);
+    let lifetimes = generate_lifetimes(builder);
+    let generics = builder
+        .operation()
+        .has_attribute_sized_operands()
+        .then(|| quote! { <'a> });
 
     quote! {
         /// Creates a builder.
-        pub fn builder(
+        pub fn builder #generics (
             context: &'c ::melior::Context,
             location: ::melior::ir::Location<'c>
-        ) -> #builder_ident<'c, #(#arguments),*> {
+        ) -> #builder_ident<#lifetimes, #(#arguments),*> {
             #builder_ident::new(context, location)
         }
     }
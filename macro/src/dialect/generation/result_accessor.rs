@@ -1,18 +1,40 @@
 use super::element_accessor::generate_element_getter;
-use crate::dialect::operation::OperationResult;
+use crate::dialect::operation::{OperationElement, OperationField, OperationResult};
 use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
 
 pub fn generate_result_accessor(
     result: &OperationResult,
     index: usize,
     length: usize,
 ) -> TokenStream {
-    generate_element_getter(
+    let getter = generate_element_getter(
         result,
         "result",
         "results",
         &Ident::new("ResultNotFound", Span::call_site()),
         index,
         length,
-    )
+    );
+    let type_getter = generate_type_getter(result);
+
+    quote! {
+        #getter
+        #type_getter
+    }
+}
+
+fn generate_type_getter(result: &OperationResult) -> TokenStream {
+    if result.is_variadic() {
+        return quote! {};
+    }
+
+    let identifier = result.singular_identifier();
+    let type_identifier = format_ident!("{}_type", identifier);
+
+    quote! {
+        pub fn #type_identifier(&self) -> Result<::melior::ir::Type<'c>, ::melior::Error> {
+            Ok(::melior::ir::ValueLike::r#type(&self.#identifier()?))
+        }
+    }
 }
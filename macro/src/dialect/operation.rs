@@ -38,6 +38,8 @@ pub struct Operation<'a> {
     summary: &'a str,
     description: String,
     can_infer_type: bool,
+    attribute_sized_results: bool,
+    attribute_sized_operands: bool,
     results: Vec<OperationResult<'a>>,
     operands: Vec<Operand<'a>>,
     regions: Vec<Region<'a>>,
@@ -57,10 +59,14 @@ impl<'a> Operation<'a> {
 
         let arguments = Self::dag_constraints(definition, "arguments")?;
         let regions = Self::collect_regions(definition)?;
+        let attribute_sized_results =
+            trait_names.contains("::mlir::OpTrait::AttrSizedResultSegments");
+        let attribute_sized_operands =
+            trait_names.contains("::mlir::OpTrait::AttrSizedOperandSegments");
         let (results, unfixed_result_count) = Self::collect_results(
             definition,
             trait_names.contains("::mlir::OpTrait::SameVariadicResultSize"),
-            trait_names.contains("::mlir::OpTrait::AttrSizedResultSegments"),
+            attribute_sized_results,
         )?;
 
         Ok(Self {
@@ -78,11 +84,13 @@ impl<'a> Operation<'a> {
                     || r#trait.name() == Some("::mlir::InferTypeOpInterface::Trait")
                         && regions.is_empty()
             }),
+            attribute_sized_results,
+            attribute_sized_operands,
             results,
             operands: Self::collect_operands(
                 &arguments,
                 trait_names.contains("::mlir::OpTrait::SameVariadicOperandSize"),
-                trait_names.contains("::mlir::OpTrait::AttrSizedOperandSegments"),
+                attribute_sized_operands,
             )?,
             regions,
             successors: Self::collect_successors(definition)?,
@@ -112,6 +120,14 @@ impl<'a> Operation<'a> {
         self.can_infer_type
     }
 
+    pub fn has_attribute_sized_results(&self) -> bool {
+        self.attribute_sized_results
+    }
+
+    pub fn has_attribute_sized_operands(&self) -> bool {
+        self.attribute_sized_operands
+    }
+
     pub fn dialect_name(&self) -> &str {
         self.dialect_name
     }
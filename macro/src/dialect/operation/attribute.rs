@@ -1,4 +1,5 @@
 use crate::dialect::{
+    enum_attribute::EnumAttribute,
     error::Error,
     operation::operation_field::OperationField,
     utility::{generate_result_type, sanitize_snake_case_identifier},
@@ -45,8 +46,12 @@ static ATTRIBUTE_TYPES: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(||
     initialize_attributes!(
         ArrayAttr => ArrayAttribute,
         Attribute => Attribute,
+        DenseBoolArrayAttr => DenseBoolArrayAttribute,
         DenseElementsAttr => DenseElementsAttribute,
+        DenseF32ArrayAttr => DenseF32ArrayAttribute,
+        DenseF64ArrayAttr => DenseF64ArrayAttribute,
         DenseI32ArrayAttr => DenseI32ArrayAttribute,
+        DenseI64ArrayAttr => DenseI64ArrayAttribute,
         FlatSymbolRefAttr => FlatSymbolRefAttribute,
         FloatAttr => FloatAttribute,
         IntegerAttr => IntegerAttribute,
@@ -67,6 +72,7 @@ pub struct Attribute<'a> {
     storage_type: Type,
     optional: bool,
     default: bool,
+    r#enum: Option<EnumAttribute>,
 }
 
 impl<'a> Attribute<'a> {
@@ -85,6 +91,7 @@ impl<'a> Attribute<'a> {
                     .unwrap_or(melior_attribute!(Attribute)),
             )?,
             storage_type_string,
+            r#enum: EnumAttribute::new(record)?,
             optional: record.bit_value("isOptional")?,
             default: match record.string_value("defaultValue") {
                 Ok(value) => !value.is_empty(),
@@ -119,6 +126,10 @@ impl<'a> Attribute<'a> {
     pub fn has_default_value(&self) -> bool {
         self.default
     }
+
+    pub fn r#enum(&self) -> Option<&EnumAttribute> {
+        self.r#enum.as_ref()
+    }
 }
 
 impl OperationField for Attribute<'_> {
@@ -137,6 +148,9 @@ impl OperationField for Attribute<'_> {
     fn parameter_type(&self) -> Type {
         if self.is_unit() {
             parse_quote!(bool)
+        } else if let Some(r#enum) = &self.r#enum {
+            let identifier = r#enum.identifier();
+            parse_quote!(#identifier)
         } else {
             let r#type = &self.storage_type;
             parse_quote!(#r#type<'c>)
@@ -146,6 +160,9 @@ impl OperationField for Attribute<'_> {
     fn return_type(&self) -> Type {
         if self.is_unit() {
             parse_quote!(bool)
+        } else if self.is_optional() {
+            let r#type = self.parameter_type();
+            generate_result_type(parse_quote!(Option<#r#type>))
         } else {
             generate_result_type(self.parameter_type())
         }
@@ -157,11 +174,18 @@ impl OperationField for Attribute<'_> {
 
     fn add_arguments(&self, name: &Ident) -> TokenStream {
         let name_string = &self.name;
+        let value = if self.is_unit() {
+            quote! { ::melior::ir::Attribute::unit(self.context) }
+        } else if self.r#enum.is_some() {
+            quote! { #name.attribute(self.context) }
+        } else {
+            quote! { #name.into() }
+        };
 
         quote! {
             &[(
                 ::melior::ir::Identifier::new(self.context, #name_string),
-                #name.into(),
+                #value,
             )]
         }
     }
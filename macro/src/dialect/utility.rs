@@ -19,6 +19,10 @@ pub fn sanitize_snake_case_identifier(name: &str) -> Result<Ident, Error> {
     sanitize_name(&name.to_case(Case::Snake))
 }
 
+pub fn sanitize_pascal_case_identifier(name: &str) -> Result<Ident, Error> {
+    sanitize_name(&name.to_case(Case::Pascal))
+}
+
 fn sanitize_name(name: &str) -> Result<Ident, Error> {
     // Replace any "." with "_".
     let mut name = name.replace('.', "_");
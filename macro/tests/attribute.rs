@@ -0,0 +1,173 @@
+mod utility;
+
+use melior::{
+    ir::{
+        attribute::{
+            DenseBoolArrayAttribute, DenseI64ArrayAttribute, StringAttribute, TypeAttribute,
+        },
+        Attribute, Location, Operation, Type,
+    },
+    Error,
+};
+use utility::*;
+
+melior_macro::dialect! {
+    name: "attribute_test",
+    td_file: "macro/tests/ods_include/attribute.td",
+}
+
+#[test]
+fn enum_attribute() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let mut operation = attribute_test::EnumOperation::builder(&context, location)
+        .predicate(attribute_test::Predicate::Ne)
+        .build();
+
+    assert_eq!(operation.predicate(), Ok(attribute_test::Predicate::Ne));
+    assert_eq!(
+        operation.as_operation().attribute("predicate"),
+        Ok(Attribute::parse(&context, "1 : i64").unwrap())
+    );
+
+    operation.set_predicate(&context, attribute_test::Predicate::Slt);
+
+    assert_eq!(operation.predicate(), Ok(attribute_test::Predicate::Slt));
+}
+
+#[test]
+fn enum_attribute_conversion() {
+    let context = create_test_context();
+
+    assert_eq!(
+        attribute_test::Predicate::try_from(attribute_test::Predicate::Eq.attribute(&context)),
+        Ok(attribute_test::Predicate::Eq)
+    );
+    assert!(
+        attribute_test::Predicate::try_from(Attribute::parse(&context, "42 : i64").unwrap())
+            .is_err()
+    );
+}
+
+#[test]
+fn dense_array_attribute() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let operation = attribute_test::DenseArrayOperation::builder(&context, location)
+        .shape(DenseI64ArrayAttribute::new(&context, &[1, 2]))
+        .flags(DenseBoolArrayAttribute::new(&context, &[true]))
+        .build();
+
+    assert_eq!(operation.shape().unwrap().element(1), Ok(2));
+    assert_eq!(operation.flags().unwrap().element(0), Ok(true));
+}
+
+#[test]
+fn optional_attribute() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let mut operation = attribute_test::OptionalOperation::builder(&context, location).build();
+
+    assert!(matches!(operation.label(), Ok(None)));
+
+    operation.set_label(StringAttribute::new(&context, "foo"));
+
+    assert_eq!(
+        operation.label().unwrap().map(Attribute::from),
+        Some(StringAttribute::new(&context, "foo").into())
+    );
+
+    operation.remove_label().unwrap();
+
+    assert!(matches!(operation.label(), Ok(None)));
+}
+
+#[test]
+fn optional_attribute_with_invalid_type() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let mut operation =
+        Operation::from(attribute_test::OptionalOperation::builder(&context, location).build());
+
+    operation.set_attribute("label", Attribute::unit(&context));
+
+    assert_eq!(
+        attribute_test::OptionalOperation::try_from(operation)
+            .unwrap()
+            .label()
+            .err(),
+        Some(Error::AttributeExpected("string", "unit".into()))
+    );
+}
+
+#[test]
+fn type_attribute() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let operation = attribute_test::TypeOperation::builder(&context, location)
+        .element_type(TypeAttribute::new(Type::index(&context)))
+        .build();
+
+    assert_eq!(
+        operation.element_type().unwrap().value(),
+        Type::index(&context)
+    );
+}
+
+#[test]
+fn unit_attribute() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let mut operation = attribute_test::UnitOperation::builder(&context, location)
+        .flag(true)
+        .build();
+
+    assert!(operation.flag());
+    assert_eq!(
+        operation.as_operation().attribute("flag"),
+        Ok(Attribute::unit(&context))
+    );
+
+    operation.set_flag(&context, false);
+
+    assert!(!operation.flag());
+    assert!(operation.as_operation().attribute("flag").is_err());
+
+    operation.set_flag(&context, true);
+
+    assert!(operation.flag());
+}
+
+#[test]
+fn unset_unit_attribute() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    assert!(!attribute_test::UnitOperation::builder(&context, location)
+        .flag(false)
+        .build()
+        .flag());
+    assert!(!attribute_test::UnitOperation::builder(&context, location)
+        .build()
+        .flag());
+}
@@ -1,6 +1,12 @@
 mod utility;
 
-use melior::ir::{Block, Location, Type};
+use melior::{
+    ir::{
+        attribute::DenseI32ArrayAttribute, operation::OperationBuilder, Block, Location, Operation,
+        Type, Value, ValueLike,
+    },
+    Error,
+};
 use utility::*;
 
 melior_macro::dialect! {
@@ -27,7 +33,22 @@ fn simple() {
 
     assert_eq!(operation.lhs().unwrap(), block.argument(0).unwrap().into());
     assert_eq!(operation.rhs().unwrap(), block.argument(1).unwrap().into());
+    assert_eq!(operation.res_type().unwrap(), r#type);
     assert_eq!(operation.as_operation().operand_count(), 2);
+    assert_eq!(
+        operation.all_operands().collect::<Vec<_>>(),
+        vec![
+            block.argument(0).unwrap().into(),
+            block.argument(1).unwrap().into()
+        ]
+    );
+    assert_eq!(
+        operation
+            .all_results()
+            .map(|result| result.r#type())
+            .collect::<Vec<_>>(),
+        vec![r#type]
+    );
 }
 
 #[test]
@@ -65,3 +86,257 @@ fn variadic_after_single() {
     assert_eq!(operation.as_operation().operand_count(), 3);
     assert_eq!(operation.others().count(), 2);
 }
+
+#[test]
+fn try_from_operation() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location), (r#type, location)]);
+    let operation = Operation::from(operand_test::simple(
+        &context,
+        r#type,
+        block.argument(0).unwrap().into(),
+        block.argument(1).unwrap().into(),
+        location,
+    ));
+
+    assert_eq!(
+        operand_test::VariadicOperation::try_from(operation.clone()).err(),
+        Some(Error::OperationExpected {
+            expected: "operand_test.variadic",
+            actual: "operand_test.simple".into(),
+        })
+    );
+    assert!(operand_test::SimpleOperation::try_from(operation).is_ok());
+}
+
+#[test]
+fn try_from_operation_with_operand_count_mismatch() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location)]);
+    let operation = OperationBuilder::new("operand_test.simple", location)
+        .add_operands(&[block.argument(0).unwrap().into()])
+        .add_results(&[r#type])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        operand_test::SimpleOperation::try_from(operation).err(),
+        Some(Error::ElementCountMismatch {
+            r#type: "operands of operand_test.simple".into(),
+            expected: 2,
+            actual: 1,
+        })
+    );
+}
+
+#[test]
+fn try_from_operation_with_operand_count_out_of_range() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let operation = OperationBuilder::new("operand_test.variadic", location)
+        .add_results(&[Type::parse(&context, "i32").unwrap()])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        operand_test::VariadicOperation::try_from(operation).err(),
+        Some(Error::ElementCountOutOfRange {
+            r#type: "operands of operand_test.variadic".into(),
+            minimum: 1,
+            maximum: None,
+            actual: 0,
+        })
+    );
+}
+
+#[test]
+fn try_from_operation_with_operand_segment_mismatch() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location), (r#type, location), (r#type, location)]);
+    let operation = OperationBuilder::new("operand_test.attribute_sized", location)
+        .add_operands(&[
+            block.argument(0).unwrap().into(),
+            block.argument(1).unwrap().into(),
+            block.argument(2).unwrap().into(),
+        ])
+        .add_attribute(
+            "operand_segment_sizes",
+            DenseI32ArrayAttribute::new(&context, &[1, 1, 2]).into(),
+        )
+        .add_results(&[r#type])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        operand_test::AttributeSizedOperation::try_from(operation).err(),
+        Some(Error::ElementCountMismatch {
+            r#type: "operand segments of operand_test.attribute_sized".into(),
+            expected: 4,
+            actual: 3,
+        })
+    );
+}
+
+#[test]
+fn from_operation_unchecked() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location), (r#type, location)]);
+    let operation = Operation::from(operand_test::simple(
+        &context,
+        r#type,
+        block.argument(0).unwrap().into(),
+        block.argument(1).unwrap().into(),
+        location,
+    ));
+
+    let operation = operand_test::SimpleOperation::from_operation_unchecked(operation);
+
+    assert_eq!(operation.lhs().unwrap(), block.argument(0).unwrap().into());
+}
+
+#[test]
+fn as_ref() {
+    fn operand_count<'c>(operation: &impl AsRef<Operation<'c>>) -> usize {
+        operation.as_ref().operand_count()
+    }
+
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location), (r#type, location)]);
+    let operation = operand_test::simple(
+        &context,
+        r#type,
+        block.argument(0).unwrap().into(),
+        block.argument(1).unwrap().into(),
+        location,
+    );
+
+    assert_eq!(operand_count(&operation), 2);
+}
+
+#[test]
+fn downcast() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location), (r#type, location)]);
+    let operation = Operation::from(operand_test::simple(
+        &context,
+        r#type,
+        block.argument(0).unwrap().into(),
+        block.argument(1).unwrap().into(),
+        location,
+    ));
+
+    let operation = operation
+        .downcast::<operand_test::VariadicOperation>()
+        .err()
+        .unwrap();
+    let operation = operation
+        .downcast::<operand_test::SimpleOperation>()
+        .unwrap();
+
+    assert_eq!(operation.lhs().unwrap(), block.argument(0).unwrap().into());
+}
+
+#[test]
+fn attribute_sized() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location), (r#type, location), (r#type, location)]);
+    let operation = operand_test::AttributeSizedOperation::builder(&context, location)
+        .res(r#type)
+        .third(&[block.argument(2).unwrap().into()])
+        .second(block.argument(1).unwrap().into())
+        .first(&[block.argument(0).unwrap().into()])
+        .build();
+
+    assert_eq!(
+        operation.as_operation().operand(0).unwrap(),
+        block.argument(0).unwrap().into()
+    );
+    assert_eq!(
+        operation.as_operation().operand(2).unwrap(),
+        block.argument(2).unwrap().into()
+    );
+    assert_eq!(
+        operation.first().unwrap().collect::<Vec<_>>(),
+        vec![Value::from(block.argument(0).unwrap())]
+    );
+    assert_eq!(
+        operation.second().unwrap(),
+        block.argument(1).unwrap().into()
+    );
+    assert_eq!(
+        operation.third().unwrap().collect::<Vec<_>>(),
+        vec![Value::from(block.argument(2).unwrap())]
+    );
+}
+
+#[test]
+fn try_build() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location), (r#type, location)]);
+    let operation = operand_test::SimpleOperation::builder(&context, location)
+        .lhs(block.argument(0).unwrap().into())
+        .rhs(block.argument(1).unwrap().into())
+        .res(r#type)
+        .try_build()
+        .unwrap();
+
+    assert_eq!(operation.as_operation().operand_count(), 2);
+}
+
+#[test]
+fn try_build_with_verification_failure() {
+    let context = create_test_context();
+
+    let location = Location::unknown(&context);
+
+    let block = Block::new(&[(Type::parse(&context, "i32").unwrap(), location)]);
+    let operation = melior::dialect::ods::arith::IndexCastOperation::builder(&context, location)
+        .r#in(block.argument(0).unwrap().into())
+        .out(Type::parse(&context, "i64").unwrap())
+        .try_build();
+
+    assert!(matches!(operation, Err(Error::Verify(_))));
+}
@@ -3,16 +3,18 @@ use crate::{
     dialect::{Dialect, DialectRegistry},
     logical_result::LogicalResult,
     string_ref::StringRef,
+    ThreadPool,
 };
 use mlir_sys::{
     mlirContextAppendDialectRegistry, mlirContextAttachDiagnosticHandler, mlirContextCreate,
-    mlirContextDestroy, mlirContextDetachDiagnosticHandler, mlirContextEnableMultithreading,
-    mlirContextEqual, mlirContextGetAllowUnregisteredDialects, mlirContextGetNumLoadedDialects,
-    mlirContextGetNumRegisteredDialects, mlirContextGetOrLoadDialect,
-    mlirContextIsRegisteredOperation, mlirContextLoadAllAvailableDialects,
-    mlirContextSetAllowUnregisteredDialects, MlirContext, MlirDiagnostic, MlirLogicalResult,
+    mlirContextCreateWithThreading, mlirContextDestroy, mlirContextDetachDiagnosticHandler,
+    mlirContextEnableMultithreading, mlirContextEqual, mlirContextGetAllowUnregisteredDialects,
+    mlirContextGetNumLoadedDialects, mlirContextGetNumRegisteredDialects,
+    mlirContextGetOrLoadDialect, mlirContextIsRegisteredOperation,
+    mlirContextLoadAllAvailableDialects, mlirContextSetAllowUnregisteredDialects,
+    mlirContextSetThreadPool, MlirContext, MlirDiagnostic, MlirLogicalResult,
 };
-use std::{ffi::c_void, marker::PhantomData};
+use std::{ffi::c_void, marker::PhantomData, mem::transmute, ops::Deref};
 
 /// A context of IR, dialects, and passes.
 ///
@@ -31,6 +33,16 @@ impl Context {
         }
     }
 
+    /// Creates a context with multi-threading enabled or disabled.
+    ///
+    /// Unlike [`enable_multi_threading`](Self::enable_multi_threading), no
+    /// thread pool is created if multi-threading is disabled.
+    pub fn new_with_threading(enabled: bool) -> Self {
+        Self {
+            raw: unsafe { mlirContextCreateWithThreading(enabled) },
+        }
+    }
+
     /// Gets a number of registered dialects.
     pub fn registered_dialect_count(&self) -> usize {
         unsafe { mlirContextGetNumRegisteredDialects(self.raw) as usize }
@@ -42,10 +54,13 @@ impl Context {
     }
 
     /// Gets or loads a dialect.
-    pub fn get_or_load_dialect(&self, name: &str) -> Dialect {
+    ///
+    /// It returns `None` if no dialect of the given name is registered in the
+    /// context.
+    pub fn get_or_load_dialect(&self, name: &str) -> Option<Dialect> {
         let name = StringRef::new(name);
 
-        unsafe { Dialect::from_raw(mlirContextGetOrLoadDialect(self.raw, name.to_raw())) }
+        unsafe { Dialect::from_option_raw(mlirContextGetOrLoadDialect(self.raw, name.to_raw())) }
     }
 
     /// Appends a dialect registry.
@@ -59,10 +74,23 @@ impl Context {
     }
 
     /// Enables multi-threading.
+    ///
+    /// Multi-threading is enabled by default with a thread pool owned by each
+    /// context.
     pub fn enable_multi_threading(&self, enabled: bool) {
         unsafe { mlirContextEnableMultithreading(self.raw, enabled) }
     }
 
+    /// Sets a thread pool shared with other contexts and enables
+    /// multi-threading with it.
+    pub fn set_thread_pool(&self, thread_pool: &'static ThreadPool) {
+        // MLIR requires multi-threading to be disabled before setting a thread
+        // pool.
+        self.enable_multi_threading(false);
+
+        unsafe { mlirContextSetThreadPool(self.raw, thread_pool.to_raw()) }
+    }
+
     /// Returns `true` if unregistered dialects are allowed.
     pub fn allow_unregistered_dialects(&self) -> bool {
         unsafe { mlirContextGetAllowUnregisteredDialects(self.raw) }
@@ -168,6 +196,14 @@ impl<'c> ContextRef<'c> {
     }
 }
 
+impl<'c> Deref for ContextRef<'c> {
+    type Target = Context;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { transmute(self) }
+    }
+}
+
 impl<'c> PartialEq for ContextRef<'c> {
     fn eq(&self, other: &Self) -> bool {
         unsafe { mlirContextEqual(self.raw, other.raw) }
@@ -185,12 +221,19 @@ impl<'c> Eq for ContextRef<'c> {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dialect::DialectHandle;
 
     #[test]
     fn new() {
         Context::new();
     }
 
+    #[test]
+    fn new_with_threading() {
+        Context::new_with_threading(false);
+        Context::new_with_threading(true);
+    }
+
     #[test]
     fn registered_dialect_count() {
         let context = Context::new();
@@ -205,6 +248,25 @@ mod tests {
         assert_eq!(context.loaded_dialect_count(), 1);
     }
 
+    #[test]
+    fn load_all_available_dialects() {
+        let context = Context::new();
+        let registry = DialectRegistry::new();
+
+        registry.insert_dialect(DialectHandle::func());
+        context.append_dialect_registry(&registry);
+
+        assert_eq!(context.loaded_dialect_count(), 1);
+
+        context.load_all_available_dialects();
+
+        assert_eq!(
+            context.loaded_dialect_count(),
+            context.registered_dialect_count()
+        );
+        assert!(context.loaded_dialect_count() > 1);
+    }
+
     #[test]
     fn append_dialect_registry() {
         let context = Context::new();
@@ -212,6 +274,26 @@ mod tests {
         context.append_dialect_registry(&DialectRegistry::new());
     }
 
+    #[test]
+    fn get_or_load_dialect() {
+        let context = Context::new();
+        let registry = DialectRegistry::new();
+
+        registry.insert_dialect(DialectHandle::func());
+        context.append_dialect_registry(&registry);
+
+        assert_eq!(
+            context.get_or_load_dialect("func").unwrap().namespace(),
+            Ok("func")
+        );
+        assert_eq!(context.loaded_dialect_count(), 2);
+    }
+
+    #[test]
+    fn get_or_load_unregistered_dialect() {
+        assert_eq!(Context::new().get_or_load_dialect("func"), None);
+    }
+
     #[test]
     fn is_registered_operation() {
         let context = Context::new();
@@ -240,6 +322,14 @@ mod tests {
         context.enable_multi_threading(false);
     }
 
+    #[test]
+    fn set_thread_pool() {
+        let thread_pool = Box::leak(Box::new(ThreadPool::new()));
+
+        Context::new().set_thread_pool(thread_pool);
+        Context::new().set_thread_pool(thread_pool);
+    }
+
     #[test]
     fn allow_unregistered_dialects() {
         let context = Context::new();
@@ -15,6 +15,7 @@ use std::{
     marker::PhantomData,
 };
 
+/// A diagnostic.
 #[derive(Debug)]
 pub struct Diagnostic<'c> {
     raw: MlirDiagnostic,
@@ -22,19 +23,23 @@ pub struct Diagnostic<'c> {
 }
 
 impl<'c> Diagnostic<'c> {
+    /// Gets a location.
     pub fn location(&self) -> Location {
         unsafe { Location::from_raw(mlirDiagnosticGetLocation(self.raw)) }
     }
 
+    /// Gets a severity.
     pub fn severity(&self) -> DiagnosticSeverity {
         DiagnosticSeverity::try_from(unsafe { mlirDiagnosticGetSeverity(self.raw) })
             .unwrap_or_else(|error| unreachable!("{}", error))
     }
 
+    /// Gets a number of notes.
     pub fn note_count(&self) -> usize {
         (unsafe { mlirDiagnosticGetNumNotes(self.raw) }) as usize
     }
 
+    /// Gets a note at a position.
     pub fn note(&self, index: usize) -> Result<Self, Error> {
         if index < self.note_count() {
             Ok(unsafe { Self::from_raw(mlirDiagnosticGetNote(self.raw, index as isize)) })
@@ -78,6 +83,7 @@ impl<'a> Display for Diagnostic<'a> {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{ir::Module, Context};
 
     #[test]
@@ -97,4 +103,58 @@ mod tests {
             "custom op 'foo' is unknown (tried 'builtin.foo' as well)"
         );
     }
+
+    #[test]
+    fn collect_diagnostics() {
+        let mut diagnostics = vec![];
+        let context = Context::new();
+
+        context.attach_diagnostic_handler(|diagnostic| {
+            diagnostics.push((diagnostic.severity(), diagnostic.to_string()));
+            true
+        });
+
+        Module::parse(&context, "foo");
+
+        assert_eq!(
+            diagnostics,
+            vec![(
+                DiagnosticSeverity::Error,
+                "custom op 'foo' is unknown (tried 'builtin.foo' as well)".into()
+            )]
+        );
+    }
+
+    #[test]
+    fn detach_diagnostic_handler() {
+        let mut count = 0;
+        let context = Context::new();
+
+        let id = context.attach_diagnostic_handler(|_| {
+            count += 1;
+            true
+        });
+
+        Module::parse(&context, "foo");
+        context.detach_diagnostic_handler(id);
+        context.attach_diagnostic_handler(|_| true);
+        Module::parse(&context, "foo");
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn diagnostic_location() {
+        let mut location = None;
+        let context = Context::new();
+
+        context.attach_diagnostic_handler(|diagnostic| {
+            location = Some(diagnostic.location().to_string());
+            true
+        });
+
+        Module::parse(&context, "foo");
+
+        assert!(location.is_some());
+    }
 }
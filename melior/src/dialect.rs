@@ -50,6 +50,24 @@ impl<'c> Dialect<'c> {
             _context: Default::default(),
         }
     }
+
+    /// Creates an optional dialect from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(dialect: MlirDialect) -> Option<Self> {
+        if dialect.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(dialect))
+        }
+    }
+
+    /// Converts a dialect into a raw object.
+    pub const fn to_raw(&self) -> MlirDialect {
+        self.raw
+    }
 }
 
 impl<'c> PartialEq for Dialect<'c> {
@@ -87,6 +105,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn equal_to_dialect_loaded_by_namespace() {
+        let context = Context::new();
+        let dialect = DialectHandle::func().load_dialect(&context);
+
+        assert_eq!(context.get_or_load_dialect("func"), Some(dialect));
+        assert_eq!(
+            context.get_or_load_dialect(dialect.namespace().unwrap()),
+            Some(dialect)
+        );
+    }
+
     #[test]
     fn not_equal() {
         let context = Context::new();
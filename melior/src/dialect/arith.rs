@@ -2,8 +2,10 @@
 
 use crate::{
     ir::{
-        attribute::IntegerAttribute, operation::OperationBuilder, r#type::IntegerType, Attribute,
-        Identifier, Location, Operation, Value, ValueLike,
+        attribute::{FloatAttribute, IntegerAttribute},
+        operation::OperationBuilder,
+        r#type::IntegerType,
+        Attribute, Identifier, Location, Operation, Type, Value, ValueLike,
     },
     Context,
 };
@@ -23,6 +25,34 @@ pub fn constant<'c>(
         .expect("valid operation")
 }
 
+/// Creates an `arith.constant` operation of an integer or index type.
+pub fn constant_integer<'c>(
+    context: &'c Context,
+    value: i64,
+    r#type: Type<'c>,
+    location: Location<'c>,
+) -> Operation<'c> {
+    constant(
+        context,
+        IntegerAttribute::new(value, r#type).into(),
+        location,
+    )
+}
+
+/// Creates an `arith.constant` operation of a float type.
+pub fn constant_float<'c>(
+    context: &'c Context,
+    value: f64,
+    r#type: Type<'c>,
+    location: Location<'c>,
+) -> Operation<'c> {
+    constant(
+        context,
+        FloatAttribute::new(context, value, r#type).into(),
+        location,
+    )
+}
+
 /// `arith.cmpf` predicate
 pub enum CmpfPredicate {
     False,
@@ -249,6 +279,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn constant_integer_value() {
+        let context = create_context();
+        let operation = constant_integer(
+            &context,
+            42,
+            Type::index(&context),
+            Location::unknown(&context),
+        );
+
+        assert!(operation.verify());
+        assert_eq!(
+            operation.attribute("value").unwrap(),
+            Attribute::parse(&context, "42 : index").unwrap()
+        );
+        assert_eq!(operation.result(0).unwrap().r#type(), Type::index(&context));
+    }
+
+    #[test]
+    fn constant_float_value() {
+        let context = create_context();
+        let operation = constant_float(
+            &context,
+            1.5,
+            Type::float32(&context),
+            Location::unknown(&context),
+        );
+
+        assert!(operation.verify());
+        assert_eq!(
+            operation.attribute("value").unwrap(),
+            Attribute::parse(&context, "1.5 : f32").unwrap()
+        );
+        assert_eq!(
+            operation.result(0).unwrap().r#type(),
+            Type::float32(&context)
+        );
+    }
+
     #[test]
     fn compile_negf() {
         let context = create_context();
@@ -1,3 +1,4 @@
+use super::DialectHandle;
 use mlir_sys::{mlirDialectRegistryCreate, mlirDialectRegistryDestroy, MlirDialectRegistry};
 
 /// A dialect registry.
@@ -14,6 +15,14 @@ impl DialectRegistry {
         }
     }
 
+    /// Inserts a dialect of a handle.
+    ///
+    /// Handles of out-of-tree dialects can be created with
+    /// [`DialectHandle::from_raw`].
+    pub fn insert_dialect(&self, handle: DialectHandle) {
+        handle.insert_dialect(self)
+    }
+
     /// Converts a dialect registry into a raw object.
     pub const fn to_raw(&self) -> MlirDialectRegistry {
         self.raw
@@ -35,7 +44,7 @@ impl Default for DialectRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{context::Context, dialect::DialectHandle};
+    use crate::context::Context;
 
     #[test]
     fn new() {
@@ -59,4 +68,18 @@ mod tests {
 
         assert_eq!(context.registered_dialect_count() - count, 1);
     }
+
+    #[test]
+    fn insert_dialect() {
+        let registry = DialectRegistry::new();
+        registry.insert_dialect(DialectHandle::func());
+        registry.insert_dialect(DialectHandle::scf());
+
+        let context = Context::new();
+        let count = context.registered_dialect_count();
+
+        context.append_dialect_registry(&registry);
+
+        assert_eq!(context.registered_dialect_count() - count, 2);
+    }
 }
@@ -2,8 +2,8 @@
 
 use crate::{
     ir::{
-        attribute::DenseI64ArrayAttribute, operation::OperationBuilder, Identifier, Location,
-        Operation, Region, Type, Value,
+        attribute::DenseI64ArrayAttribute, operation::OperationBuilder, Block, Identifier,
+        Location, Operation, Region, Type, Value, ValueLike,
     },
     Context,
 };
@@ -49,6 +49,59 @@ pub fn r#for<'c>(
         .expect("valid operation")
 }
 
+/// Creates a `scf.for` operation with iteration arguments.
+///
+/// Its results have the same types as the initial values of the iteration
+/// arguments.
+pub fn for_with_iter_args<'c>(
+    start: Value<'c, '_>,
+    end: Value<'c, '_>,
+    step: Value<'c, '_>,
+    initial_values: &[Value<'c, '_>],
+    region: Region<'c>,
+    location: Location<'c>,
+) -> Operation<'c> {
+    OperationBuilder::new("scf.for", location)
+        .add_operands(&[start, end, step])
+        .add_operands(initial_values)
+        .add_results(
+            &initial_values
+                .iter()
+                .map(|value| value.r#type())
+                .collect::<Vec<_>>(),
+        )
+        .add_regions([region])
+        .build()
+        .expect("valid operation")
+}
+
+/// Creates a body region of a `scf.for` operation.
+///
+/// Its entry block takes an induction variable and iteration arguments of
+/// given types in order. Values returned by a body function are yielded by a
+/// `scf.yield` operation appended to the end of the block.
+pub fn for_region<'c>(
+    induction_variable_type: Type<'c>,
+    iter_arg_types: &[Type<'c>],
+    location: Location<'c>,
+    body: impl for<'a> FnOnce(&'a Block<'c>) -> Vec<Value<'c, 'a>>,
+) -> Region<'c> {
+    let block = Block::new(
+        &[induction_variable_type]
+            .iter()
+            .chain(iter_arg_types)
+            .map(|&r#type| (r#type, location))
+            .collect::<Vec<_>>(),
+    );
+    let values = body(&block);
+
+    block.append_operation(r#yield(&values, location));
+
+    let region = Region::new();
+    region.append_block(block);
+    region
+}
+
 /// Creates a `scf.if` operation.
 pub fn r#if<'c>(
     condition: Value<'c, '_>,
@@ -236,6 +289,96 @@ mod tests {
         insta::assert_display_snapshot!(module.as_operation());
     }
 
+    #[test]
+    fn compile_for_with_iter_args() {
+        let context = Context::new();
+        load_all_dialects(&context);
+
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let index_type = Type::index(&context);
+
+        module.body().append_operation(func::func(
+            &context,
+            StringAttribute::new(&context, "foo"),
+            TypeAttribute::new(FunctionType::new(&context, &[], &[index_type]).into()),
+            {
+                let block = Block::new(&[]);
+
+                let start = block.append_operation(arith::constant(
+                    &context,
+                    Attribute::parse(&context, "0 : index").unwrap(),
+                    location,
+                ));
+
+                let end = block.append_operation(arith::constant(
+                    &context,
+                    Attribute::parse(&context, "8 : index").unwrap(),
+                    location,
+                ));
+
+                let step = block.append_operation(arith::constant(
+                    &context,
+                    Attribute::parse(&context, "1 : index").unwrap(),
+                    location,
+                ));
+
+                let operation = block.append_operation(for_with_iter_args(
+                    start.result(0).unwrap().into(),
+                    end.result(0).unwrap().into(),
+                    step.result(0).unwrap().into(),
+                    &[start.result(0).unwrap().into()],
+                    for_region(index_type, &[index_type], location, |block| {
+                        let sum = block.append_operation(arith::addi(
+                            block.argument(0).unwrap().into(),
+                            block.argument(1).unwrap().into(),
+                            location,
+                        ));
+
+                        vec![sum.result(0).unwrap().into()]
+                    }),
+                    location,
+                ));
+
+                block.append_operation(func::r#return(
+                    &[operation.result(0).unwrap().into()],
+                    location,
+                ));
+
+                let region = Region::new();
+                region.append_block(block);
+                region
+            },
+            &[],
+            location,
+        ));
+
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    fn for_region_arguments() {
+        let context = Context::new();
+        load_all_dialects(&context);
+
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let float_type = Type::float64(&context);
+
+        let region = for_region(index_type, &[float_type], location, |block| {
+            vec![block.argument(1).unwrap().into()]
+        });
+        let block = region.first_block().unwrap();
+
+        assert_eq!(block.argument_count(), 2);
+        assert_eq!(block.argument(0).unwrap().r#type(), index_type);
+        assert_eq!(block.argument(1).unwrap().r#type(), float_type);
+        assert_eq!(
+            block.terminator().unwrap().name(),
+            Identifier::new(&context, "scf.yield")
+        );
+    }
+
     mod r#if {
         use super::*;
 
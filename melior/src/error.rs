@@ -8,15 +8,37 @@ use std::{
 /// A Melior error.
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
+    ArgumentLengthMismatch {
+        name: &'static str,
+        expected: usize,
+        actual: usize,
+    },
     AttributeExpected(&'static str, String),
     AttributeNotFound(String),
     BlockArgumentExpected(String),
+    ElementCountMismatch {
+        r#type: String,
+        expected: usize,
+        actual: usize,
+    },
+    ElementCountOutOfRange {
+        r#type: String,
+        minimum: usize,
+        maximum: Option<usize>,
+        actual: usize,
+    },
     ElementExpected {
         r#type: &'static str,
         value: String,
     },
+    InferResultTypes,
+    InvalidType(&'static str),
     InvokeFunction,
     OperationBuild,
+    OperationExpected {
+        expected: &'static str,
+        actual: String,
+    },
     OperandNotFound(&'static str),
     OperationResultExpected(String),
     PositionOutOfBounds {
@@ -24,17 +46,39 @@ pub enum Error {
         value: String,
         index: usize,
     },
+    ParseAttribute(String),
+    ParseModule(String),
     ParsePassPipeline(String),
+    ParseType(String),
+    Print(String),
+    ReadFile {
+        path: String,
+        message: String,
+    },
+    ReplaceAllSymbolUses(String),
     ResultNotFound(&'static str),
-    RunPass,
+    RunPass(String),
     TypeExpected(&'static str, String),
     UnknownDiagnosticSeverity(u32),
     Utf8(Utf8Error),
+    ValueInUse(String),
+    Verify(String),
+    WriteBytecode(String),
 }
 
 impl Display for Error {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
+            Self::ArgumentLengthMismatch {
+                name,
+                expected,
+                actual,
+            } => {
+                write!(
+                    formatter,
+                    "{expected} elements expected for argument {name} but got {actual}"
+                )
+            }
             Self::AttributeExpected(r#type, attribute) => {
                 write!(formatter, "{type} attribute expected: {attribute}")
             }
@@ -44,29 +88,84 @@ impl Display for Error {
             Self::BlockArgumentExpected(value) => {
                 write!(formatter, "block argument expected: {value}")
             }
+            Self::ElementCountMismatch {
+                r#type,
+                expected,
+                actual,
+            } => {
+                write!(
+                    formatter,
+                    "{expected} elements expected for {type} but got {actual}"
+                )
+            }
+            Self::ElementCountOutOfRange {
+                r#type,
+                minimum,
+                maximum,
+                actual,
+            } => {
+                if let Some(maximum) = maximum {
+                    write!(
+                        formatter,
+                        "{minimum} to {maximum} elements expected for {type} but got {actual}"
+                    )
+                } else {
+                    write!(
+                        formatter,
+                        "at least {minimum} elements expected for {type} but got {actual}"
+                    )
+                }
+            }
             Self::ElementExpected { r#type, value } => {
                 write!(formatter, "element of {type} type expected: {value}")
             }
+            Self::InferResultTypes => write!(formatter, "failed to infer result types"),
+            Self::InvalidType(name) => {
+                write!(formatter, "invalid {name} type")
+            }
             Self::InvokeFunction => write!(formatter, "failed to invoke JIT-compiled function"),
             Self::OperationBuild => {
                 write!(formatter, "operation build failed")
             }
+            Self::OperationExpected { expected, actual } => {
+                write!(formatter, "{expected} operation expected but got {actual}")
+            }
             Self::OperandNotFound(name) => {
                 write!(formatter, "operand {name} not found")
             }
             Self::OperationResultExpected(value) => {
                 write!(formatter, "operation result expected: {value}")
             }
+            Self::ParseAttribute(message) => {
+                write!(formatter, "failed to parse attribute:\n{}", message)
+            }
+            Self::ParseModule(message) => {
+                write!(formatter, "failed to parse module:\n{}", message)
+            }
             Self::ParsePassPipeline(message) => {
                 write!(formatter, "failed to parse pass pipeline:\n{}", message)
             }
+            Self::ParseType(message) => {
+                write!(formatter, "failed to parse type:\n{}", message)
+            }
+            Self::Print(message) => {
+                write!(formatter, "failed to print: {message}")
+            }
             Self::PositionOutOfBounds { name, value, index } => {
                 write!(formatter, "{name} position {index} out of bounds: {value}")
             }
+            Self::ReadFile { path, message } => {
+                write!(formatter, "failed to read file {path}: {message}")
+            }
+            Self::ReplaceAllSymbolUses(symbol) => {
+                write!(formatter, "failed to replace all uses of symbol {symbol}")
+            }
             Self::ResultNotFound(name) => {
                 write!(formatter, "result {name} not found")
             }
-            Self::RunPass => write!(formatter, "failed to run pass"),
+            Self::RunPass(message) => {
+                write!(formatter, "failed to run pass:\n{}", message)
+            }
             Self::TypeExpected(r#type, actual) => {
                 write!(formatter, "{type} type expected: {actual}")
             }
@@ -76,6 +175,15 @@ impl Display for Error {
             Self::Utf8(error) => {
                 write!(formatter, "{}", error)
             }
+            Self::ValueInUse(value) => {
+                write!(formatter, "value in use: {value}")
+            }
+            Self::Verify(message) => {
+                write!(formatter, "failed to verify:\n{}", message)
+            }
+            Self::WriteBytecode(message) => {
+                write!(formatter, "failed to write bytecode: {message}")
+            }
         }
     }
 }
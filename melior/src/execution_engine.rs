@@ -68,7 +68,10 @@ impl ExecutionEngine {
     ///
     /// This function makes a pointer accessible to the execution engine. If a
     /// given pointer is invalid or misaligned, calling this function might
-    /// result in undefined behavior.
+    /// result in undefined behavior. A given pointer must also stay valid
+    /// while the execution engine is alive and, if it is a function pointer,
+    /// its function must have a C calling convention and a signature matching
+    /// its declaration in a module.
     pub unsafe fn register_symbol(&self, name: &str, ptr: *mut ()) {
         mlirExecutionEngineRegisterSymbol(self.raw, StringRef::new(name).to_raw(), ptr as _);
     }
@@ -138,6 +141,57 @@ mod tests {
         assert_eq!(result, 84);
     }
 
+    #[test]
+    fn register_symbol() {
+        extern "C" fn triple(value: i32) -> i32 {
+            value * 3
+        }
+
+        let context = create_test_context();
+
+        let mut module = Module::parse(
+            &context,
+            r#"
+            module {
+                func.func private @triple(i32) -> i32
+
+                func.func @call(%arg0 : i32) -> i32 attributes { llvm.emit_c_interface } {
+                    %res = func.call @triple(%arg0) : (i32) -> i32
+                    return %res : i32
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let pass_manager = pass::PassManager::new(&context);
+        pass_manager.add_pass(pass::conversion::create_func_to_llvm());
+
+        assert_eq!(pass_manager.run(&mut module), Ok(()));
+
+        let engine = ExecutionEngine::new(&module, 2, &[], false);
+
+        unsafe { engine.register_symbol("triple", triple as *mut ()) };
+
+        let mut argument = 14;
+        let mut result = -1;
+
+        assert_eq!(
+            unsafe {
+                engine.invoke_packed(
+                    "call",
+                    &mut [
+                        &mut argument as *mut i32 as *mut (),
+                        &mut result as *mut i32 as *mut (),
+                    ],
+                )
+            },
+            Ok(())
+        );
+
+        assert_eq!(result, 42);
+    }
+
     #[test]
     fn dump_to_object_file() {
         let context = create_test_context();
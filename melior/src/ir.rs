@@ -1,25 +1,35 @@
 //! IR objects and builders.
 
+mod affine_expr;
 mod affine_map;
 pub mod attribute;
 pub mod block;
+mod builder;
 mod identifier;
+mod integer_set;
+mod ir_mapping;
 mod location;
 mod module;
 pub mod operation;
 mod region;
+pub mod symbol_table;
 pub mod r#type;
 mod value;
 
 pub use self::{
+    affine_expr::AffineExpr,
     affine_map::AffineMap,
     attribute::{Attribute, AttributeLike},
     block::{Block, BlockRef},
+    builder::Builder,
     identifier::Identifier,
+    integer_set::IntegerSet,
+    ir_mapping::IrMapping,
     location::Location,
     module::Module,
     operation::{Operation, OperationRef},
     r#type::{ShapedTypeLike, Type, TypeLike},
     region::{Region, RegionRef},
+    symbol_table::SymbolTable,
     value::{Value, ValueLike},
 };
@@ -0,0 +1,173 @@
+use crate::{
+    context::{Context, ContextRef},
+    utility::print_callback,
+};
+use mlir_sys::{
+    mlirAffineAddExprGet, mlirAffineConstantExprGet, mlirAffineDimExprGet, mlirAffineExprDump,
+    mlirAffineExprEqual, mlirAffineExprGetContext, mlirAffineExprPrint, mlirAffineMulExprGet,
+    mlirAffineSymbolExprGet, MlirAffineExpr,
+};
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+    ops::{Add, Mul},
+};
+
+/// An affine expression.
+#[derive(Clone, Copy)]
+pub struct AffineExpr<'c> {
+    raw: MlirAffineExpr,
+    _context: PhantomData<&'c Context>,
+}
+
+impl<'c> AffineExpr<'c> {
+    /// Creates a dimension expression.
+    pub fn dimension(context: &'c Context, position: usize) -> Self {
+        unsafe { Self::from_raw(mlirAffineDimExprGet(context.to_raw(), position as isize)) }
+    }
+
+    /// Creates a symbol expression.
+    pub fn symbol(context: &'c Context, position: usize) -> Self {
+        unsafe { Self::from_raw(mlirAffineSymbolExprGet(context.to_raw(), position as isize)) }
+    }
+
+    /// Creates a constant expression.
+    pub fn constant(context: &'c Context, value: i64) -> Self {
+        unsafe { Self::from_raw(mlirAffineConstantExprGet(context.to_raw(), value)) }
+    }
+
+    /// Gets a context.
+    pub fn context(&self) -> ContextRef<'c> {
+        unsafe { ContextRef::from_raw(mlirAffineExprGetContext(self.raw)) }
+    }
+
+    /// Dumps an affine expression.
+    pub fn dump(&self) {
+        unsafe { mlirAffineExprDump(self.raw) }
+    }
+
+    /// Creates an affine expression from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirAffineExpr) -> Self {
+        Self {
+            raw,
+            _context: Default::default(),
+        }
+    }
+
+    /// Converts an affine expression into a raw object.
+    pub const fn to_raw(self) -> MlirAffineExpr {
+        self.raw
+    }
+}
+
+impl<'c> Add for AffineExpr<'c> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineAddExprGet(self.raw, other.raw)) }
+    }
+}
+
+impl<'c> Mul for AffineExpr<'c> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineMulExprGet(self.raw, other.raw)) }
+    }
+}
+
+impl<'c> PartialEq for AffineExpr<'c> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirAffineExprEqual(self.raw, other.raw) }
+    }
+}
+
+impl<'c> Eq for AffineExpr<'c> {}
+
+impl<'c> Display for AffineExpr<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let mut data = (formatter, Ok(()));
+
+        unsafe {
+            mlirAffineExprPrint(
+                self.raw,
+                Some(print_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1
+    }
+}
+
+impl<'c> Debug for AffineExpr<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn dimension() {
+        let context = Context::new();
+
+        assert_eq!(AffineExpr::dimension(&context, 1).to_string(), "d1");
+    }
+
+    #[test]
+    fn symbol() {
+        let context = Context::new();
+
+        assert_eq!(AffineExpr::symbol(&context, 1).to_string(), "s1");
+    }
+
+    #[test]
+    fn constant() {
+        let context = Context::new();
+
+        assert_eq!(AffineExpr::constant(&context, 42).to_string(), "42");
+    }
+
+    #[test]
+    fn add() {
+        let context = Context::new();
+
+        assert_eq!(
+            (AffineExpr::dimension(&context, 0) + AffineExpr::symbol(&context, 0)).to_string(),
+            "d0 + s0"
+        );
+    }
+
+    #[test]
+    fn mul() {
+        let context = Context::new();
+
+        assert_eq!(
+            (AffineExpr::dimension(&context, 0) * AffineExpr::constant(&context, 2)).to_string(),
+            "d0 * 2"
+        );
+    }
+
+    #[test]
+    fn equal() {
+        let context = Context::new();
+
+        assert_eq!(
+            AffineExpr::dimension(&context, 0),
+            AffineExpr::dimension(&context, 0)
+        );
+        assert_ne!(
+            AffineExpr::dimension(&context, 0),
+            AffineExpr::dimension(&context, 1)
+        );
+    }
+}
@@ -1,10 +1,13 @@
+use super::AffineExpr;
 use crate::{
     context::{Context, ContextRef},
     utility::print_callback,
 };
 use mlir_sys::{
-    mlirAffineMapDump, mlirAffineMapEqual, mlirAffineMapGetContext, mlirAffineMapPrint,
-    MlirAffineMap,
+    mlirAffineMapConstantGet, mlirAffineMapDump, mlirAffineMapEqual, mlirAffineMapGet,
+    mlirAffineMapGetContext, mlirAffineMapGetNumDims, mlirAffineMapGetNumResults,
+    mlirAffineMapGetNumSymbols, mlirAffineMapIsPermutation, mlirAffineMapMultiDimIdentityGet,
+    mlirAffineMapPrint, MlirAffineMap,
 };
 use std::{
     ffi::c_void,
@@ -20,6 +23,63 @@ pub struct AffineMap<'c> {
 }
 
 impl<'c> AffineMap<'c> {
+    /// Creates an affine map.
+    pub fn new(
+        context: &'c Context,
+        dimension_count: usize,
+        symbol_count: usize,
+        expressions: &[AffineExpr<'c>],
+    ) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+                expressions.len() as isize,
+                expressions
+                    .iter()
+                    .map(|expression| expression.to_raw())
+                    .collect::<Vec<_>>()
+                    .as_ptr() as *mut _,
+            ))
+        }
+    }
+
+    /// Creates a constant affine map.
+    pub fn constant(context: &'c Context, value: i64) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapConstantGet(context.to_raw(), value)) }
+    }
+
+    /// Creates an identity affine map.
+    pub fn identity(context: &'c Context, dimension_count: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapMultiDimIdentityGet(
+                context.to_raw(),
+                dimension_count as isize,
+            ))
+        }
+    }
+
+    /// Gets a number of dimensions.
+    pub fn dimension_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumDims(self.raw) as usize }
+    }
+
+    /// Gets a number of symbols.
+    pub fn symbol_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumSymbols(self.raw) as usize }
+    }
+
+    /// Gets a number of results.
+    pub fn result_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumResults(self.raw) as usize }
+    }
+
+    /// Checks if an affine map is a permutation.
+    pub fn is_permutation(&self) -> bool {
+        unsafe { mlirAffineMapIsPermutation(self.raw) }
+    }
+
     /// Gets a context.
     pub fn context(&self) -> ContextRef<'c> {
         unsafe { ContextRef::from_raw(mlirAffineMapGetContext(self.raw)) }
@@ -41,6 +101,11 @@ impl<'c> AffineMap<'c> {
             _context: Default::default(),
         }
     }
+
+    /// Converts an affine map into a raw object.
+    pub const fn to_raw(self) -> MlirAffineMap {
+        self.raw
+    }
 }
 
 impl<'c> PartialEq for AffineMap<'c> {
@@ -72,3 +137,79 @@ impl<'c> Debug for AffineMap<'c> {
         Display::fmt(self, formatter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        let map = AffineMap::new(
+            &context,
+            2,
+            1,
+            &[
+                AffineExpr::dimension(&context, 1),
+                AffineExpr::dimension(&context, 0) + AffineExpr::symbol(&context, 0),
+            ],
+        );
+
+        assert_eq!(map.to_string(), "(d0, d1)[s0] -> (d1, d0 + s0)");
+        assert_eq!(map.dimension_count(), 2);
+        assert_eq!(map.symbol_count(), 1);
+        assert_eq!(map.result_count(), 2);
+    }
+
+    #[test]
+    fn constant() {
+        let context = Context::new();
+
+        assert_eq!(AffineMap::constant(&context, 42).to_string(), "() -> (42)");
+    }
+
+    #[test]
+    fn identity() {
+        let context = Context::new();
+        let map = AffineMap::identity(&context, 2);
+
+        assert_eq!(map.to_string(), "(d0, d1) -> (d0, d1)");
+        assert!(map.is_permutation());
+    }
+
+    #[test]
+    fn is_permutation() {
+        let context = Context::new();
+
+        assert!(AffineMap::new(
+            &context,
+            2,
+            0,
+            &[
+                AffineExpr::dimension(&context, 1),
+                AffineExpr::dimension(&context, 0)
+            ],
+        )
+        .is_permutation());
+        assert!(
+            !AffineMap::new(&context, 2, 0, &[AffineExpr::dimension(&context, 0)],)
+                .is_permutation()
+        );
+    }
+
+    #[test]
+    fn equal() {
+        let context = Context::new();
+
+        assert_eq!(
+            AffineMap::identity(&context, 2),
+            AffineMap::identity(&context, 2)
+        );
+        assert_ne!(
+            AffineMap::identity(&context, 1),
+            AffineMap::identity(&context, 2)
+        );
+    }
+}
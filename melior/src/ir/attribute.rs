@@ -4,22 +4,36 @@
 mod r#macro;
 mod array;
 mod attribute_like;
+mod dense_bool_array;
 mod dense_elements;
+mod dense_f32_array;
+mod dense_f64_array;
 mod dense_i32_array;
 mod dense_i64_array;
+mod dense_resource_elements;
+mod dictionary;
 mod flat_symbol_ref;
 mod float;
 mod integer;
 mod string;
+mod symbol_ref;
 mod r#type;
 
 pub use self::{
-    array::ArrayAttribute, attribute_like::AttributeLike, dense_elements::DenseElementsAttribute,
+    array::ArrayAttribute, attribute_like::AttributeLike,
+    dense_bool_array::DenseBoolArrayAttribute, dense_elements::DenseElementsAttribute,
+    dense_f32_array::DenseF32ArrayAttribute, dense_f64_array::DenseF64ArrayAttribute,
     dense_i32_array::DenseI32ArrayAttribute, dense_i64_array::DenseI64ArrayAttribute,
+    dense_resource_elements::DenseResourceElementsAttribute, dictionary::DictionaryAttribute,
     flat_symbol_ref::FlatSymbolRefAttribute, float::FloatAttribute, integer::IntegerAttribute,
-    r#type::TypeAttribute, string::StringAttribute,
+    r#type::TypeAttribute, string::StringAttribute, symbol_ref::SymbolRefAttribute,
+};
+use crate::{
+    context::Context,
+    string_ref::StringRef,
+    utility::{collect_diagnostics, print_callback, write_callback},
+    Error,
 };
-use crate::{context::Context, string_ref::StringRef, utility::print_callback};
 use mlir_sys::{
     mlirAttributeEqual, mlirAttributeGetNull, mlirAttributeParseGet, mlirAttributePrint,
     mlirUnitAttrGet, MlirAttribute,
@@ -27,6 +41,8 @@ use mlir_sys::{
 use std::{
     ffi::c_void,
     fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    io::{self, Write},
     marker::PhantomData,
 };
 
@@ -49,15 +65,55 @@ impl<'c> Attribute<'c> {
         }
     }
 
+    /// Parses an attribute reporting diagnostics on failure.
+    pub fn try_parse(context: &'c Context, source: &str) -> Result<Self, Error> {
+        let (attribute, diagnostics) =
+            collect_diagnostics(context, || Self::parse(context, source));
+
+        attribute.ok_or_else(|| Error::ParseAttribute(diagnostics.join("\n")))
+    }
+
     /// Creates a unit attribute.
     pub fn unit(context: &'c Context) -> Self {
         unsafe { Self::from_raw(mlirUnitAttrGet(context.to_raw())) }
     }
 
+    downcast_functions!(
+        as_array: ArrayAttribute,
+        as_dense_bool_array: DenseBoolArrayAttribute,
+        as_dense_elements: DenseElementsAttribute,
+        as_dense_f32_array: DenseF32ArrayAttribute,
+        as_dense_f64_array: DenseF64ArrayAttribute,
+        as_dense_i32_array: DenseI32ArrayAttribute,
+        as_dense_i64_array: DenseI64ArrayAttribute,
+        as_dictionary: DictionaryAttribute,
+        as_flat_symbol_ref: FlatSymbolRefAttribute,
+        as_float: FloatAttribute,
+        as_integer: IntegerAttribute,
+        as_string: StringAttribute,
+        as_symbol_ref: SymbolRefAttribute,
+        as_type: TypeAttribute,
+    );
+
     pub(crate) unsafe fn null() -> Self {
         unsafe { Self::from_raw(mlirAttributeGetNull()) }
     }
 
+    /// Prints an attribute to a writer.
+    pub fn print<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        let mut data: (_, io::Result<()>) = (writer, Ok(()));
+
+        unsafe {
+            mlirAttributePrint(
+                self.raw,
+                Some(write_callback::<W>),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1.map_err(|error| Error::Print(error.to_string()))
+    }
+
     /// Creates an attribute from a raw object.
     ///
     /// # Safety
@@ -98,6 +154,13 @@ impl<'c> PartialEq for Attribute<'c> {
 
 impl<'c> Eq for Attribute<'c> {}
 
+// See the `Hash` implementation of `Type`.
+impl<'c> Hash for Attribute<'c> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.raw.ptr.hash(hasher);
+    }
+}
+
 impl<'c> Display for Attribute<'c> {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         let mut data = (formatter, Ok(()));
@@ -123,13 +186,19 @@ impl<'c> Debug for Attribute<'c> {
 from_subtypes!(
     Attribute,
     ArrayAttribute,
+    DenseBoolArrayAttribute,
     DenseElementsAttribute,
+    DenseF32ArrayAttribute,
+    DenseF64ArrayAttribute,
     DenseI32ArrayAttribute,
     DenseI64ArrayAttribute,
+    DenseResourceElementsAttribute,
+    DictionaryAttribute,
     FlatSymbolRefAttribute,
     FloatAttribute,
     IntegerAttribute,
     StringAttribute,
+    SymbolRefAttribute,
     TypeAttribute,
 );
 
@@ -140,6 +209,7 @@ mod tests {
         ir::{Type, TypeLike},
         test::create_test_context,
     };
+    use std::collections::HashMap;
 
     #[test]
     fn parse() {
@@ -158,6 +228,26 @@ mod tests {
         assert!(Attribute::parse(&Context::new(), "z").is_none());
     }
 
+    #[test]
+    fn try_parse() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::try_parse(&context, "unit"),
+            Ok(Attribute::unit(&context))
+        );
+    }
+
+    #[test]
+    fn try_parse_error() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            Attribute::try_parse(&context, "z"),
+            Err(Error::ParseAttribute(message)) if message.contains("expected attribute value")
+        ));
+    }
+
     #[test]
     fn context() {
         let context = create_test_context();
@@ -174,15 +264,17 @@ mod tests {
         );
     }
 
-    // TODO Fix this.
-    #[ignore]
     #[test]
     fn type_id() {
         let context = Context::new();
 
         assert_eq!(
             Attribute::parse(&context, "42 : index").unwrap().type_id(),
-            Type::index(&context).id()
+            Attribute::parse(&context, "13 : i32").unwrap().type_id()
+        );
+        assert_ne!(
+            Attribute::parse(&context, "42 : index").unwrap().type_id(),
+            Attribute::parse(&context, "\"foo\"").unwrap().type_id()
         );
     }
 
@@ -294,6 +386,21 @@ mod tests {
         assert!(Attribute::parse(&context, "@foo").unwrap().is_symbol_ref());
     }
 
+    #[test]
+    fn downcast() {
+        let context = create_test_context();
+        let attribute = Attribute::parse(&context, "42 : i64").unwrap();
+
+        assert_eq!(
+            attribute
+                .as_integer()
+                .map(|attribute| attribute.value_i64()),
+            Some(Ok(42))
+        );
+        assert!(attribute.as_float().is_none());
+        assert!(attribute.as_string().is_none());
+    }
+
     #[test]
     fn equal() {
         let context = create_test_context();
@@ -312,6 +419,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hash_map_key() {
+        let context = create_test_context();
+        let names = HashMap::from([
+            (Attribute::unit(&context), "unit"),
+            (Attribute::parse(&context, "42 : i64").unwrap(), "integer"),
+        ]);
+
+        assert_eq!(
+            names.get(&Attribute::parse(&context, "unit").unwrap()),
+            Some(&"unit")
+        );
+        assert_eq!(
+            names.get(&Attribute::parse(&context, "42").unwrap()),
+            Some(&"integer")
+        );
+        assert_eq!(names.get(&Attribute::parse(&context, "43").unwrap()), None);
+    }
+
     #[test]
     fn display() {
         let context = create_test_context();
@@ -320,4 +446,14 @@ mod tests {
             "unit"
         );
     }
+
+    #[test]
+    fn print() {
+        let context = create_test_context();
+        let mut buffer = vec![];
+
+        Attribute::unit(&context).print(&mut buffer).unwrap();
+
+        assert_eq!(buffer, b"unit");
+    }
 }
@@ -49,9 +49,14 @@ impl<'c> ArrayAttribute<'c> {
             })
         }
     }
+
+    /// Gets elements.
+    pub fn elements(&self) -> impl ExactSizeIterator<Item = Attribute<'c>> + '_ {
+        (0..self.len()).map(|index| self.element(index).expect("valid array index"))
+    }
 }
 
-attribute_traits!(ArrayAttribute, is_dense_i64_array, "dense i64 array");
+attribute_traits!(ArrayAttribute, is_array, "array");
 
 #[cfg(test)]
 mod tests {
@@ -93,4 +98,30 @@ mod tests {
 
         assert_eq!(attribute.len(), 1);
     }
+
+    #[test]
+    fn elements() {
+        let context = create_test_context();
+        let r#type = Type::index(&context);
+        let attributes = [
+            IntegerAttribute::new(1, r#type).into(),
+            IntegerAttribute::new(2, r#type).into(),
+        ];
+
+        let attribute = ArrayAttribute::new(&context, &attributes);
+
+        assert_eq!(attribute.elements().len(), 2);
+        assert_eq!(attribute.elements().collect::<Vec<_>>(), attributes);
+    }
+
+    #[test]
+    fn try_from_attribute() {
+        let context = create_test_context();
+
+        assert!(ArrayAttribute::try_from(Attribute::parse(&context, "[1, 2]").unwrap()).is_ok());
+        assert_eq!(
+            ArrayAttribute::try_from(Attribute::unit(&context)).map(Attribute::from),
+            Err(Error::AttributeExpected("array", "unit".into()))
+        );
+    }
 }
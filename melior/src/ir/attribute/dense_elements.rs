@@ -1,12 +1,20 @@
 use super::{Attribute, AttributeLike};
 use crate::{
-    ir::{Type, TypeLike},
+    ir::{r#type::IntegerType, Type, TypeLike},
     Error,
 };
 use mlir_sys::{
-    mlirDenseElementsAttrGet, mlirDenseElementsAttrGetInt32Value,
-    mlirDenseElementsAttrGetInt64Value, mlirElementsAttrGetNumElements, MlirAttribute,
+    mlirDenseElementsAttrBoolGet, mlirDenseElementsAttrDoubleGet, mlirDenseElementsAttrFloatGet,
+    mlirDenseElementsAttrGet, mlirDenseElementsAttrGetBoolSplatValue,
+    mlirDenseElementsAttrGetBoolValue, mlirDenseElementsAttrGetDoubleSplatValue,
+    mlirDenseElementsAttrGetDoubleValue, mlirDenseElementsAttrGetFloatValue,
+    mlirDenseElementsAttrGetInt32Value, mlirDenseElementsAttrGetInt64SplatValue,
+    mlirDenseElementsAttrGetInt64Value, mlirDenseElementsAttrInt32Get,
+    mlirDenseElementsAttrInt64Get, mlirDenseElementsAttrIsSplat, mlirDenseElementsAttrReshapeGet,
+    mlirElementsAttrGetNumElements, mlirShapedTypeGetDimSize, mlirShapedTypeGetElementType,
+    mlirShapedTypeGetRank, mlirShapedTypeHasStaticShape, MlirAttribute, MlirType,
 };
+use std::ffi::c_int;
 
 /// A dense elements attribute.
 #[derive(Clone, Copy)]
@@ -30,6 +38,92 @@ impl<'c> DenseElementsAttribute<'c> {
         }
     }
 
+    /// Creates a dense elements attribute from i32 values.
+    pub fn new_from_i32_slice(r#type: Type<'c>, values: &[i32]) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            values,
+            "i32",
+            |r#type| is_integer(r#type, 32),
+            mlirDenseElementsAttrInt32Get,
+        )
+    }
+
+    /// Creates a dense elements attribute from i64 values.
+    pub fn new_from_i64_slice(r#type: Type<'c>, values: &[i64]) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            values,
+            "i64",
+            |r#type| r#type.is_index() || is_integer(r#type, 64),
+            mlirDenseElementsAttrInt64Get,
+        )
+    }
+
+    /// Creates a dense elements attribute from f32 values.
+    pub fn new_from_f32_slice(r#type: Type<'c>, values: &[f32]) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            values,
+            "f32",
+            |r#type| r#type.is_f32(),
+            mlirDenseElementsAttrFloatGet,
+        )
+    }
+
+    /// Creates a dense elements attribute from f64 values.
+    pub fn new_from_f64_slice(r#type: Type<'c>, values: &[f64]) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            values,
+            "f64",
+            |r#type| r#type.is_f64(),
+            mlirDenseElementsAttrDoubleGet,
+        )
+    }
+
+    /// Creates a dense elements attribute from boolean values.
+    ///
+    /// Values are packed into the 1-bit integer representation by MLIR.
+    pub fn new_from_bool_slice(r#type: Type<'c>, values: &[bool]) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            &values
+                .iter()
+                .map(|&value| value as c_int)
+                .collect::<Vec<_>>(),
+            "boolean",
+            |r#type| is_integer(r#type, 1),
+            mlirDenseElementsAttrBoolGet,
+        )
+    }
+
+    /// Reshapes an attribute into a type with the same element type and
+    /// number of elements.
+    pub fn reshape(&self, r#type: Type<'c>) -> Result<Self, Error> {
+        let count = Self::element_count(r#type)?;
+        let element_type = |r#type: Type<'c>| unsafe {
+            Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw()))
+        };
+
+        if element_type(r#type) != element_type(self.r#type()) {
+            Err(Error::TypeExpected("matching element", r#type.to_string()))
+        } else if count == self.len() {
+            Ok(unsafe {
+                Self::from_raw(mlirDenseElementsAttrReshapeGet(
+                    self.attribute.to_raw(),
+                    r#type.to_raw(),
+                ))
+            })
+        } else {
+            Err(Error::ElementCountMismatch {
+                r#type: r#type.to_string(),
+                expected: self.len(),
+                actual: count,
+            })
+        }
+    }
+
     /// Gets a length.
     pub fn len(&self) -> usize {
         (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
@@ -40,18 +134,103 @@ impl<'c> DenseElementsAttribute<'c> {
         self.len() == 0
     }
 
+    /// Checks if all elements are the same.
+    pub fn is_splat(&self) -> bool {
+        unsafe { mlirDenseElementsAttrIsSplat(self.attribute.to_raw()) }
+    }
+
     /// Gets an i32 element.
-    // TODO Prevent calling these type specific methods on other types.
     pub fn i32_element(&self, index: usize) -> Result<i32, Error> {
-        if !self.is_dense_int_elements() {
+        self.element(
+            index,
+            "i32",
+            is_integer(self.element_type(), 32),
+            mlirDenseElementsAttrGetInt32Value,
+        )
+    }
+
+    /// Gets an i64 element.
+    pub fn i64_element(&self, index: usize) -> Result<i64, Error> {
+        self.element(
+            index,
+            "i64",
+            self.is_i64_elements(),
+            mlirDenseElementsAttrGetInt64Value,
+        )
+    }
+
+    /// Gets an f32 element.
+    pub fn f32_element(&self, index: usize) -> Result<f32, Error> {
+        self.element(
+            index,
+            "f32",
+            self.element_type().is_f32(),
+            mlirDenseElementsAttrGetFloatValue,
+        )
+    }
+
+    /// Gets an f64 element.
+    pub fn f64_element(&self, index: usize) -> Result<f64, Error> {
+        self.element(
+            index,
+            "f64",
+            self.element_type().is_f64(),
+            mlirDenseElementsAttrGetDoubleValue,
+        )
+    }
+
+    /// Gets a boolean element.
+    pub fn bool_element(&self, index: usize) -> Result<bool, Error> {
+        self.element(
+            index,
+            "boolean",
+            is_integer(self.element_type(), 1),
+            mlirDenseElementsAttrGetBoolValue,
+        )
+    }
+
+    /// Gets an i64 splat element.
+    pub fn i64_splat_element(&self) -> Result<i64, Error> {
+        self.splat_element(
+            "i64",
+            self.is_i64_elements(),
+            mlirDenseElementsAttrGetInt64SplatValue,
+        )
+    }
+
+    /// Gets an f64 splat element.
+    pub fn f64_splat_element(&self) -> Result<f64, Error> {
+        self.splat_element(
+            "f64",
+            self.element_type().is_f64(),
+            mlirDenseElementsAttrGetDoubleSplatValue,
+        )
+    }
+
+    /// Gets a boolean splat element.
+    pub fn bool_splat_element(&self) -> Result<bool, Error> {
+        self.splat_element(
+            "boolean",
+            is_integer(self.element_type(), 1),
+            mlirDenseElementsAttrGetBoolSplatValue,
+        )
+        .map(|value| value != 0)
+    }
+
+    fn element<T>(
+        &self,
+        index: usize,
+        r#type: &'static str,
+        is_valid_type: bool,
+        get: unsafe extern "C" fn(MlirAttribute, isize) -> T,
+    ) -> Result<T, Error> {
+        if !is_valid_type {
             Err(Error::ElementExpected {
-                r#type: "integer",
+                r#type,
                 value: self.to_string(),
             })
         } else if index < self.len() {
-            Ok(unsafe {
-                mlirDenseElementsAttrGetInt32Value(self.attribute.to_raw(), index as isize)
-            })
+            Ok(unsafe { get(self.attribute.to_raw(), index as isize) })
         } else {
             Err(Error::PositionOutOfBounds {
                 name: "dense element",
@@ -61,25 +240,84 @@ impl<'c> DenseElementsAttribute<'c> {
         }
     }
 
-    /// Gets an i64 element.
-    pub fn i64_element(&self, index: usize) -> Result<i64, Error> {
-        if !self.is_dense_int_elements() {
+    fn splat_element<T>(
+        &self,
+        r#type: &'static str,
+        is_valid_type: bool,
+        get: unsafe extern "C" fn(MlirAttribute) -> T,
+    ) -> Result<T, Error> {
+        if !is_valid_type {
             Err(Error::ElementExpected {
-                r#type: "integer",
+                r#type,
                 value: self.to_string(),
             })
-        } else if index < self.len() {
+        } else if self.is_splat() {
+            Ok(unsafe { get(self.attribute.to_raw()) })
+        } else {
+            Err(Error::AttributeExpected("splat", self.to_string()))
+        }
+    }
+
+    fn element_type(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirShapedTypeGetElementType(self.r#type().to_raw())) }
+    }
+
+    // Index elements are stored as 64-bit integers.
+    fn is_i64_elements(&self) -> bool {
+        let r#type = self.element_type();
+
+        r#type.is_index() || is_integer(r#type, 64)
+    }
+
+    fn from_slice<T>(
+        r#type: Type<'c>,
+        values: &[T],
+        element_type: &'static str,
+        is_valid_type: impl Fn(Type<'c>) -> bool,
+        create: unsafe extern "C" fn(MlirType, isize, *const T) -> MlirAttribute,
+    ) -> Result<Self, Error> {
+        let count = Self::element_count(r#type)?;
+
+        if !is_valid_type(unsafe { Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw())) })
+        {
+            Err(Error::ElementExpected {
+                r#type: element_type,
+                value: r#type.to_string(),
+            })
+        } else if values.len() == count {
             Ok(unsafe {
-                mlirDenseElementsAttrGetInt64Value(self.attribute.to_raw(), index as isize)
+                Self::from_raw(create(
+                    r#type.to_raw(),
+                    values.len() as isize,
+                    values.as_ptr(),
+                ))
             })
         } else {
-            Err(Error::PositionOutOfBounds {
-                name: "dense element",
-                value: self.to_string(),
-                index,
+            Err(Error::ElementCountMismatch {
+                r#type: r#type.to_string(),
+                expected: count,
+                actual: values.len(),
             })
         }
     }
+
+    pub(super) fn element_count(r#type: Type<'c>) -> Result<usize, Error> {
+        if !r#type.is_shaped() {
+            return Err(Error::TypeExpected("shaped", r#type.to_string()));
+        } else if !unsafe { mlirShapedTypeHasStaticShape(r#type.to_raw()) } {
+            return Err(Error::TypeExpected("statically shaped", r#type.to_string()));
+        }
+
+        Ok((0..unsafe { mlirShapedTypeGetRank(r#type.to_raw()) })
+            .map(|index| unsafe { mlirShapedTypeGetDimSize(r#type.to_raw(), index) } as usize)
+            .product())
+    }
+}
+
+pub(super) fn is_integer(r#type: Type, width: u32) -> bool {
+    IntegerType::try_from(r#type)
+        .map(|r#type| r#type.width() == width)
+        .unwrap_or_default()
 }
 
 attribute_traits!(DenseElementsAttribute, is_dense_elements, "dense elements");
@@ -90,7 +328,7 @@ mod tests {
     use crate::{
         ir::{
             attribute::IntegerAttribute,
-            r#type::{IntegerType, MemRefType},
+            r#type::{IntegerType, MemRefType, RankedTensorType},
         },
         test::create_test_context,
     };
@@ -141,6 +379,149 @@ mod tests {
         );
     }
 
+    #[test]
+    fn f64_element() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_f64_slice(
+            RankedTensorType::new(&[2], Type::float64(&context), None).into(),
+            &[1.0, 2.0],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.f64_element(0), Ok(1.0));
+        assert_eq!(attribute.f64_element(1), Ok(2.0));
+        assert_eq!(
+            attribute.i64_element(0),
+            Err(Error::ElementExpected {
+                r#type: "i64",
+                value: attribute.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn element_with_mismatched_width() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_i64_slice(
+            RankedTensorType::new(&[2], IntegerType::new(&context, 64).into(), None).into(),
+            &[1, 2],
+        )
+        .unwrap();
+
+        assert_eq!(
+            attribute.i32_element(0),
+            Err(Error::ElementExpected {
+                r#type: "i32",
+                value: attribute.to_string(),
+            })
+        );
+        assert_eq!(
+            attribute.bool_element(0),
+            Err(Error::ElementExpected {
+                r#type: "boolean",
+                value: attribute.to_string(),
+            })
+        );
+
+        let attribute = DenseElementsAttribute::new_from_f64_slice(
+            RankedTensorType::new(&[2], Type::float64(&context), None).into(),
+            &[1.0, 2.0],
+        )
+        .unwrap();
+
+        assert_eq!(
+            attribute.f32_element(0),
+            Err(Error::ElementExpected {
+                r#type: "f32",
+                value: attribute.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn splat_element_with_mismatched_width() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_i32_slice(
+            RankedTensorType::new(&[2], IntegerType::new(&context, 32).into(), None).into(),
+            &[1, 1],
+        )
+        .unwrap();
+
+        assert_eq!(
+            attribute.i64_splat_element(),
+            Err(Error::ElementExpected {
+                r#type: "i64",
+                value: attribute.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn f32_element() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_f32_slice(
+            RankedTensorType::new(&[2], Type::float32(&context), None).into(),
+            &[1.0, 2.0],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.f32_element(1), Ok(2.0));
+    }
+
+    #[test]
+    fn bool_element() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_bool_slice(
+            RankedTensorType::new(&[2], IntegerType::new(&context, 1).into(), None).into(),
+            &[true, false],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.bool_element(0), Ok(true));
+        assert_eq!(attribute.bool_element(1), Ok(false));
+    }
+
+    #[test]
+    fn splat_element() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_i64_slice(
+            RankedTensorType::new(&[3], IntegerType::new(&context, 64).into(), None).into(),
+            &[42, 42, 42],
+        )
+        .unwrap();
+
+        assert!(attribute.is_splat());
+        assert_eq!(attribute.i64_splat_element(), Ok(42));
+    }
+
+    #[test]
+    fn splat_element_with_non_splat() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_f64_slice(
+            RankedTensorType::new(&[2], Type::float64(&context), None).into(),
+            &[1.0, 2.0],
+        )
+        .unwrap();
+
+        assert!(!attribute.is_splat());
+        assert_eq!(
+            attribute.f64_splat_element(),
+            Err(Error::AttributeExpected("splat", attribute.to_string()))
+        );
+    }
+
+    #[test]
+    fn bool_splat_element() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_bool_slice(
+            RankedTensorType::new(&[2], IntegerType::new(&context, 1).into(), None).into(),
+            &[true, true],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.bool_splat_element(), Ok(true));
+    }
+
     #[test]
     fn len() {
         let context = create_test_context();
@@ -153,4 +534,199 @@ mod tests {
 
         assert_eq!(attribute.len(), 3);
     }
+
+    #[test]
+    fn new_from_i32_slice() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_i32_slice(
+            RankedTensorType::new(&[3], IntegerType::new(&context, 32).into(), None).into(),
+            &[1, 2, 3],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.i32_element(0), Ok(1));
+        assert_eq!(attribute.i32_element(1), Ok(2));
+        assert_eq!(attribute.i32_element(2), Ok(3));
+    }
+
+    #[test]
+    fn new_from_i64_slice() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_i64_slice(
+            RankedTensorType::new(&[2, 2], IntegerType::new(&context, 64).into(), None).into(),
+            &[1, 2, 3, 4],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.len(), 4);
+        assert_eq!(attribute.i64_element(3), Ok(4));
+    }
+
+    #[test]
+    fn new_from_f32_slice() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(
+                DenseElementsAttribute::new_from_f32_slice(
+                    RankedTensorType::new(&[2], Type::float32(&context), None).into(),
+                    &[1.0, 2.0],
+                )
+                .unwrap()
+            ),
+            Attribute::parse(&context, "dense<[1.0, 2.0]> : tensor<2xf32>").unwrap()
+        );
+    }
+
+    #[test]
+    fn new_from_f64_slice() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(
+                DenseElementsAttribute::new_from_f64_slice(
+                    RankedTensorType::new(&[2], Type::float64(&context), None).into(),
+                    &[1.0, 2.0],
+                )
+                .unwrap()
+            ),
+            Attribute::parse(&context, "dense<[1.0, 2.0]> : tensor<2xf64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn new_from_bool_slice() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(
+                DenseElementsAttribute::new_from_bool_slice(
+                    RankedTensorType::new(&[3], IntegerType::new(&context, 1).into(), None).into(),
+                    &[true, false, true],
+                )
+                .unwrap()
+            ),
+            Attribute::parse(&context, "dense<[true, false, true]> : tensor<3xi1>").unwrap()
+        );
+    }
+
+    #[test]
+    fn new_from_slice_with_mismatched_length() {
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[3], IntegerType::new(&context, 32).into(), None);
+
+        assert_eq!(
+            DenseElementsAttribute::new_from_i32_slice(r#type.into(), &[1, 2]).unwrap_err(),
+            Error::ElementCountMismatch {
+                r#type: r#type.to_string(),
+                expected: 3,
+                actual: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn new_from_slice_with_invalid_element_type() {
+        let context = create_test_context();
+        let integer_type = RankedTensorType::new(&[2], IntegerType::new(&context, 8).into(), None);
+        let float_type = RankedTensorType::new(&[2], Type::float64(&context), None);
+
+        assert_eq!(
+            DenseElementsAttribute::new_from_i64_slice(integer_type.into(), &[1, 2]).unwrap_err(),
+            Error::ElementExpected {
+                r#type: "i64",
+                value: integer_type.to_string(),
+            }
+        );
+        assert_eq!(
+            DenseElementsAttribute::new_from_i32_slice(float_type.into(), &[1, 2]).unwrap_err(),
+            Error::ElementExpected {
+                r#type: "i32",
+                value: float_type.to_string(),
+            }
+        );
+        assert_eq!(
+            DenseElementsAttribute::new_from_f32_slice(float_type.into(), &[1.0, 2.0]).unwrap_err(),
+            Error::ElementExpected {
+                r#type: "f32",
+                value: float_type.to_string(),
+            }
+        );
+        assert_eq!(
+            DenseElementsAttribute::new_from_bool_slice(integer_type.into(), &[true, false])
+                .unwrap_err(),
+            Error::ElementExpected {
+                r#type: "boolean",
+                value: integer_type.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn reshape() {
+        let context = create_test_context();
+        let element_type = IntegerType::new(&context, 64).into();
+        let attribute = DenseElementsAttribute::new_from_i64_slice(
+            RankedTensorType::new(&[4], element_type, None).into(),
+            &[1, 2, 3, 4],
+        )
+        .unwrap();
+
+        assert_eq!(
+            Attribute::from(
+                attribute
+                    .reshape(RankedTensorType::new(&[2, 2], element_type, None).into())
+                    .unwrap()
+            ),
+            Attribute::parse(&context, "dense<[[1, 2], [3, 4]]> : tensor<2x2xi64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn reshape_with_mismatched_length() {
+        let context = create_test_context();
+        let element_type = IntegerType::new(&context, 64).into();
+        let attribute = DenseElementsAttribute::new_from_i64_slice(
+            RankedTensorType::new(&[4], element_type, None).into(),
+            &[1, 2, 3, 4],
+        )
+        .unwrap();
+        let r#type = RankedTensorType::new(&[3], element_type, None);
+
+        assert_eq!(
+            attribute.reshape(r#type.into()).unwrap_err(),
+            Error::ElementCountMismatch {
+                r#type: r#type.to_string(),
+                expected: 4,
+                actual: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn reshape_with_mismatched_element_type() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::new_from_i64_slice(
+            RankedTensorType::new(&[2], IntegerType::new(&context, 64).into(), None).into(),
+            &[1, 2],
+        )
+        .unwrap();
+        let r#type = RankedTensorType::new(&[2], IntegerType::new(&context, 32).into(), None);
+
+        assert_eq!(
+            attribute.reshape(r#type.into()).unwrap_err(),
+            Error::TypeExpected("matching element", r#type.to_string())
+        );
+    }
+
+    #[test]
+    fn new_from_slice_with_non_shaped_type() {
+        let context = create_test_context();
+
+        assert_eq!(
+            DenseElementsAttribute::new_from_i32_slice(IntegerType::new(&context, 32).into(), &[])
+                .unwrap_err(),
+            Error::TypeExpected("shaped", "i32".into())
+        );
+    }
 }
@@ -0,0 +1,363 @@
+use super::{dense_elements::is_integer, Attribute, AttributeLike, DenseElementsAttribute};
+use crate::{
+    ir::{Type, TypeLike},
+    string_ref::StringRef,
+    Error,
+};
+use mlir_sys::{
+    mlirAttributeGetType, mlirDenseDoubleResourceElementsAttrGetValue,
+    mlirDenseFloatResourceElementsAttrGetValue, mlirDenseInt32ResourceElementsAttrGetValue,
+    mlirDenseInt64ResourceElementsAttrGetValue, mlirElementsAttrGetNumElements,
+    mlirShapedTypeGetElementType, mlirUnmanagedDenseDoubleResourceElementsAttrGet,
+    mlirUnmanagedDenseFloatResourceElementsAttrGet, mlirUnmanagedDenseInt32ResourceElementsAttrGet,
+    mlirUnmanagedDenseInt64ResourceElementsAttrGet, MlirAttribute, MlirStringRef, MlirType,
+};
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// A dense resource elements attribute.
+///
+/// Its elements are stored in a blob referenced by a resource handle rather
+/// than in the attribute itself. Therefore, it is printed as
+/// `dense_resource<name>` in IR and the blob is not inlined into it.
+///
+/// Note that the MLIR C API does not expose a type check nor a handle name of
+/// dense resource elements attributes yet. Therefore, they can only be created
+/// and not be converted from generic attributes.
+#[derive(Clone, Copy)]
+pub struct DenseResourceElementsAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DenseResourceElementsAttribute<'c> {
+    /// Creates a dense resource elements attribute from i32 values.
+    ///
+    /// # Safety
+    ///
+    /// The values are not copied. They must outlive a context the attribute
+    /// belongs to.
+    pub unsafe fn new_from_i32_slice(
+        r#type: Type<'c>,
+        name: &str,
+        values: &[i32],
+    ) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            name,
+            values,
+            "i32",
+            |r#type| is_integer(r#type, 32),
+            mlirUnmanagedDenseInt32ResourceElementsAttrGet,
+        )
+    }
+
+    /// Creates a dense resource elements attribute from i64 values.
+    ///
+    /// # Safety
+    ///
+    /// The values are not copied. They must outlive a context the attribute
+    /// belongs to.
+    pub unsafe fn new_from_i64_slice(
+        r#type: Type<'c>,
+        name: &str,
+        values: &[i64],
+    ) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            name,
+            values,
+            "i64",
+            |r#type| is_integer(r#type, 64),
+            mlirUnmanagedDenseInt64ResourceElementsAttrGet,
+        )
+    }
+
+    /// Creates a dense resource elements attribute from f32 values.
+    ///
+    /// # Safety
+    ///
+    /// The values are not copied. They must outlive a context the attribute
+    /// belongs to.
+    pub unsafe fn new_from_f32_slice(
+        r#type: Type<'c>,
+        name: &str,
+        values: &[f32],
+    ) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            name,
+            values,
+            "f32",
+            |r#type| r#type.is_f32(),
+            mlirUnmanagedDenseFloatResourceElementsAttrGet,
+        )
+    }
+
+    /// Creates a dense resource elements attribute from f64 values.
+    ///
+    /// # Safety
+    ///
+    /// The values are not copied. They must outlive a context the attribute
+    /// belongs to.
+    pub unsafe fn new_from_f64_slice(
+        r#type: Type<'c>,
+        name: &str,
+        values: &[f64],
+    ) -> Result<Self, Error> {
+        Self::from_slice(
+            r#type,
+            name,
+            values,
+            "f64",
+            |r#type| r#type.is_f64(),
+            mlirUnmanagedDenseDoubleResourceElementsAttrGet,
+        )
+    }
+
+    /// Gets an element type.
+    pub fn element_type(&self) -> Type<'c> {
+        unsafe {
+            Type::from_raw(mlirShapedTypeGetElementType(mlirAttributeGetType(
+                self.attribute.to_raw(),
+            )))
+        }
+    }
+
+    /// Gets a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if an attribute is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets an i32 element.
+    pub fn i32_element(&self, index: usize) -> Result<i32, Error> {
+        self.element(
+            index,
+            "i32",
+            is_integer(self.element_type(), 32),
+            mlirDenseInt32ResourceElementsAttrGetValue,
+        )
+    }
+
+    /// Gets an i64 element.
+    pub fn i64_element(&self, index: usize) -> Result<i64, Error> {
+        self.element(
+            index,
+            "i64",
+            is_integer(self.element_type(), 64),
+            mlirDenseInt64ResourceElementsAttrGetValue,
+        )
+    }
+
+    /// Gets an f32 element.
+    pub fn f32_element(&self, index: usize) -> Result<f32, Error> {
+        self.element(
+            index,
+            "f32",
+            self.element_type().is_f32(),
+            mlirDenseFloatResourceElementsAttrGetValue,
+        )
+    }
+
+    /// Gets an f64 element.
+    pub fn f64_element(&self, index: usize) -> Result<f64, Error> {
+        self.element(
+            index,
+            "f64",
+            self.element_type().is_f64(),
+            mlirDenseDoubleResourceElementsAttrGetValue,
+        )
+    }
+
+    /// Creates a dense resource elements attribute from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirAttribute) -> Self {
+        Self {
+            attribute: Attribute::from_raw(raw),
+        }
+    }
+
+    fn element<T>(
+        &self,
+        index: usize,
+        r#type: &'static str,
+        is_valid_type: bool,
+        get: unsafe extern "C" fn(MlirAttribute, isize) -> T,
+    ) -> Result<T, Error> {
+        if !is_valid_type {
+            Err(Error::ElementExpected {
+                r#type,
+                value: self.to_string(),
+            })
+        } else if index < self.len() {
+            Ok(unsafe { get(self.attribute.to_raw(), index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dense resource element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    unsafe fn from_slice<T>(
+        r#type: Type<'c>,
+        name: &str,
+        values: &[T],
+        element_type: &'static str,
+        is_valid_type: impl Fn(Type<'c>) -> bool,
+        create: unsafe extern "C" fn(MlirType, MlirStringRef, isize, *const T) -> MlirAttribute,
+    ) -> Result<Self, Error> {
+        let count = DenseElementsAttribute::element_count(r#type)?;
+
+        if !is_valid_type(Type::from_raw(mlirShapedTypeGetElementType(
+            r#type.to_raw(),
+        ))) {
+            Err(Error::ElementExpected {
+                r#type: element_type,
+                value: r#type.to_string(),
+            })
+        } else if values.len() == count {
+            Ok(Self::from_raw(create(
+                r#type.to_raw(),
+                StringRef::new(name).to_raw(),
+                values.len() as isize,
+                values.as_ptr(),
+            )))
+        } else {
+            Err(Error::ElementCountMismatch {
+                r#type: r#type.to_string(),
+                expected: count,
+                actual: values.len(),
+            })
+        }
+    }
+}
+
+impl<'c> AttributeLike<'c> for DenseResourceElementsAttribute<'c> {
+    fn to_raw(&self) -> MlirAttribute {
+        self.attribute.to_raw()
+    }
+}
+
+impl<'c> Display for DenseResourceElementsAttribute<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.attribute, formatter)
+    }
+}
+
+impl<'c> Debug for DenseResourceElementsAttribute<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::r#type::{IntegerType, RankedTensorType},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn new_from_i32_slice() {
+        static VALUES: [i32; 3] = [1, 2, 3];
+
+        let context = create_test_context();
+        let attribute = unsafe {
+            DenseResourceElementsAttribute::new_from_i32_slice(
+                RankedTensorType::new(&[3], IntegerType::new(&context, 32).into(), None).into(),
+                "foo",
+                &VALUES,
+            )
+        }
+        .unwrap();
+
+        assert_eq!(attribute.len(), 3);
+        assert_eq!(
+            attribute.element_type(),
+            IntegerType::new(&context, 32).into()
+        );
+        assert_eq!(attribute.i32_element(0), Ok(1));
+        assert_eq!(attribute.i32_element(2), Ok(3));
+        assert_eq!(
+            attribute.i32_element(3),
+            Err(Error::PositionOutOfBounds {
+                name: "dense resource element",
+                value: attribute.to_string(),
+                index: 3,
+            })
+        );
+        assert!(attribute.to_string().starts_with("dense_resource<foo>"));
+    }
+
+    #[test]
+    fn new_from_f64_slice() {
+        static VALUES: [f64; 2] = [1.0, 2.0];
+
+        let context = create_test_context();
+        let attribute = unsafe {
+            DenseResourceElementsAttribute::new_from_f64_slice(
+                RankedTensorType::new(&[2], Type::float64(&context), None).into(),
+                "foo",
+                &VALUES,
+            )
+        }
+        .unwrap();
+
+        assert_eq!(attribute.f64_element(1), Ok(2.0));
+        assert_eq!(
+            attribute.i64_element(0),
+            Err(Error::ElementExpected {
+                r#type: "i64",
+                value: attribute.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn new_with_invalid_element_type() {
+        static VALUES: [f32; 2] = [1.0, 2.0];
+
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[2], Type::float64(&context), None);
+
+        assert_eq!(
+            unsafe {
+                DenseResourceElementsAttribute::new_from_f32_slice(r#type.into(), "foo", &VALUES)
+            }
+            .unwrap_err(),
+            Error::ElementExpected {
+                r#type: "f32",
+                value: r#type.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn new_with_mismatched_length() {
+        static VALUES: [i64; 2] = [1, 2];
+
+        let context = create_test_context();
+        let r#type = RankedTensorType::new(&[3], IntegerType::new(&context, 64).into(), None);
+
+        assert_eq!(
+            unsafe {
+                DenseResourceElementsAttribute::new_from_i64_slice(r#type.into(), "foo", &VALUES)
+            }
+            .unwrap_err(),
+            Error::ElementCountMismatch {
+                r#type: r#type.to_string(),
+                expected: 3,
+                actual: 2,
+            }
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::Identifier, string_ref::StringRef, Context, Error};
+use mlir_sys::{
+    mlirDictionaryAttrGet, mlirDictionaryAttrGetElement, mlirDictionaryAttrGetElementByName,
+    mlirDictionaryAttrGetNumElements, mlirNamedAttributeGet, MlirAttribute,
+};
+
+/// A dictionary attribute.
+#[derive(Clone, Copy)]
+pub struct DictionaryAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DictionaryAttribute<'c> {
+    /// Creates a dictionary attribute.
+    pub fn new(context: &'c Context, values: &[(Identifier<'c>, Attribute<'c>)]) -> Self {
+        let values = values
+            .iter()
+            .map(|(name, attribute)| unsafe {
+                mlirNamedAttributeGet(name.to_raw(), attribute.to_raw())
+            })
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirDictionaryAttrGet(
+                context.to_raw(),
+                values.len() as isize,
+                values.as_ptr(),
+            ))
+        }
+    }
+
+    /// Gets a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirDictionaryAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if a dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets an element at a position.
+    pub fn element(&self, index: usize) -> Result<(Identifier<'c>, Attribute<'c>), Error> {
+        if index < self.len() {
+            unsafe {
+                let named_attribute =
+                    mlirDictionaryAttrGetElement(self.attribute.to_raw(), index as isize);
+
+                Ok((
+                    Identifier::from_raw(named_attribute.name),
+                    Attribute::from_raw(named_attribute.attribute),
+                ))
+            }
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dictionary element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Gets an element with a name.
+    pub fn element_by_name(&self, name: &str) -> Result<Attribute<'c>, Error> {
+        unsafe {
+            Attribute::from_option_raw(mlirDictionaryAttrGetElementByName(
+                self.attribute.to_raw(),
+                StringRef::new(name).to_raw(),
+            ))
+        }
+        .ok_or(Error::AttributeNotFound(name.into()))
+    }
+
+    /// Gets elements.
+    pub fn elements(&self) -> impl Iterator<Item = (Identifier<'c>, Attribute<'c>)> + '_ {
+        (0..self.len()).map(|index| self.element(index).expect("valid dictionary index"))
+    }
+}
+
+attribute_traits!(DictionaryAttribute, is_dictionary, "dictionary");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{attribute::IntegerAttribute, Type},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(DictionaryAttribute::new(
+                &context,
+                &[(Identifier::new(&context, "foo"), Attribute::unit(&context))]
+            )),
+            Attribute::parse(&context, "{foo}").unwrap()
+        );
+    }
+
+    #[test]
+    fn len() {
+        let context = create_test_context();
+
+        assert_eq!(DictionaryAttribute::new(&context, &[]).len(), 0);
+        assert!(DictionaryAttribute::new(&context, &[]).is_empty());
+    }
+
+    #[test]
+    fn element() {
+        let context = create_test_context();
+        let identifier = Identifier::new(&context, "foo");
+        let attribute = IntegerAttribute::new(42, Type::index(&context)).into();
+        let dictionary = DictionaryAttribute::new(&context, &[(identifier, attribute)]);
+
+        assert_eq!(dictionary.element(0), Ok((identifier, attribute)));
+        assert_eq!(
+            dictionary.element(1),
+            Err(Error::PositionOutOfBounds {
+                name: "dictionary element",
+                value: dictionary.to_string(),
+                index: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn element_by_name() {
+        let context = create_test_context();
+        let attribute = IntegerAttribute::new(42, Type::index(&context)).into();
+        let dictionary =
+            DictionaryAttribute::new(&context, &[(Identifier::new(&context, "foo"), attribute)]);
+
+        assert_eq!(dictionary.element_by_name("foo"), Ok(attribute));
+        assert_eq!(
+            dictionary.element_by_name("bar"),
+            Err(Error::AttributeNotFound("bar".into()))
+        );
+    }
+
+    #[test]
+    fn elements() {
+        let context = create_test_context();
+        let attribute = Attribute::unit(&context);
+
+        assert_eq!(
+            DictionaryAttribute::new(
+                &context,
+                &[
+                    (Identifier::new(&context, "foo"), attribute),
+                    (Identifier::new(&context, "bar"), attribute),
+                ]
+            )
+            .elements()
+            .map(|(name, _)| name.as_string_ref().as_str().unwrap().to_owned())
+            .collect::<Vec<_>>(),
+            // Dictionary elements are sorted by names.
+            vec!["bar", "foo"]
+        );
+    }
+}
@@ -19,6 +19,11 @@ impl<'c> FlatSymbolRefAttribute<'c> {
         }
     }
 
+    /// Gets a symbol name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol name is not valid UTF-8.
     pub fn value(&self) -> &str {
         unsafe { StringRef::from_raw(mlirFlatSymbolRefAttrGetValue(self.to_raw())) }
             .as_str()
@@ -3,7 +3,7 @@ use crate::{
     ir::{Type, TypeLike},
     Context, Error,
 };
-use mlir_sys::{mlirFloatAttrDoubleGet, MlirAttribute};
+use mlir_sys::{mlirFloatAttrDoubleGet, mlirFloatAttrGetValueDouble, MlirAttribute};
 
 /// A float attribute.
 #[derive(Clone, Copy)]
@@ -22,6 +22,54 @@ impl<'c> FloatAttribute<'c> {
             ))
         }
     }
+
+    /// Gets a value.
+    ///
+    /// Values of any float types are converted into `f64`. The conversion
+    /// itself is exact for `bf16`, `f16`, and `f32` types. However, values of
+    /// those narrower types have been rounded on attribute creation. So, for
+    /// example, an `f16` attribute created from `0.1` returns
+    /// `0.0999755859375`.
+    pub fn value(&self) -> f64 {
+        unsafe { mlirFloatAttrGetValueDouble(self.to_raw()) }
+    }
 }
 
 attribute_traits!(FloatAttribute, is_float, "float");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value() {
+        let context = Context::new();
+
+        assert_eq!(
+            FloatAttribute::new(&context, 42.5, Type::float64(&context)).value(),
+            42.5
+        );
+    }
+
+    #[test]
+    fn value_of_narrow_float() {
+        let context = Context::new();
+
+        assert_eq!(
+            FloatAttribute::new(&context, 0.1, Type::float16(&context)).value(),
+            0.0999755859375
+        );
+        assert_eq!(
+            FloatAttribute::new(&context, 1.5, Type::bfloat16(&context)).value(),
+            1.5
+        );
+    }
+
+    #[test]
+    fn r#type() {
+        let context = Context::new();
+        let r#type = Type::float32(&context);
+
+        assert_eq!(FloatAttribute::new(&context, 42.0, r#type).r#type(), r#type);
+    }
+}
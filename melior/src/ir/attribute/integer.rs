@@ -1,9 +1,12 @@
 use super::{Attribute, AttributeLike};
 use crate::{
-    ir::{Type, TypeLike},
+    ir::{r#type::IntegerType, Type, TypeLike},
     Error,
 };
-use mlir_sys::{mlirIntegerAttrGet, MlirAttribute};
+use mlir_sys::{
+    mlirIntegerAttrGet, mlirIntegerAttrGetValueInt, mlirIntegerAttrGetValueSInt,
+    mlirIntegerAttrGetValueUInt, MlirAttribute,
+};
 
 /// An integer attribute.
 #[derive(Clone, Copy)]
@@ -16,6 +19,115 @@ impl<'c> IntegerAttribute<'c> {
     pub fn new(integer: i64, r#type: Type<'c>) -> Self {
         unsafe { Self::from_raw(mlirIntegerAttrGet(r#type.to_raw(), integer)) }
     }
+
+    /// Gets a value of a signless integer or index type.
+    pub fn value_i64(&self) -> Result<i64, Error> {
+        if self.r#type().is_index() || self.has_integer_type(|r#type| r#type.is_signless()) {
+            Ok(unsafe { mlirIntegerAttrGetValueInt(self.to_raw()) })
+        } else {
+            Err(Error::TypeExpected(
+                "signless integer",
+                self.r#type().to_string(),
+            ))
+        }
+    }
+
+    /// Gets a value of a signed integer type.
+    pub fn value_signed(&self) -> Result<i64, Error> {
+        if self.has_integer_type(|r#type| r#type.is_signed()) {
+            Ok(unsafe { mlirIntegerAttrGetValueSInt(self.to_raw()) })
+        } else {
+            Err(Error::TypeExpected(
+                "signed integer",
+                self.r#type().to_string(),
+            ))
+        }
+    }
+
+    /// Gets a value of an unsigned integer type.
+    pub fn value_unsigned(&self) -> Result<u64, Error> {
+        if self.has_integer_type(|r#type| r#type.is_unsigned()) {
+            Ok(unsafe { mlirIntegerAttrGetValueUInt(self.to_raw()) })
+        } else {
+            Err(Error::TypeExpected(
+                "unsigned integer",
+                self.r#type().to_string(),
+            ))
+        }
+    }
+
+    fn has_integer_type(&self, predicate: impl Fn(&IntegerType) -> bool) -> bool {
+        IntegerType::try_from(self.r#type())
+            .map(|r#type| predicate(&r#type))
+            .unwrap_or_default()
+    }
 }
 
 attribute_traits!(IntegerAttribute, is_integer, "integer");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn value_i64() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerAttribute::new(-42, IntegerType::new(&context, 64).into()).value_i64(),
+            Ok(-42)
+        );
+        assert_eq!(
+            IntegerAttribute::new(42, Type::index(&context)).value_i64(),
+            Ok(42)
+        );
+    }
+
+    #[test]
+    fn value_signed() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerAttribute::new(-42, IntegerType::signed(&context, 32).into()).value_signed(),
+            Ok(-42)
+        );
+    }
+
+    #[test]
+    fn value_unsigned() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerAttribute::new(42, IntegerType::unsigned(&context, 8).into()).value_unsigned(),
+            Ok(42)
+        );
+    }
+
+    #[test]
+    fn value_with_invalid_signedness() {
+        let context = Context::new();
+        let attribute = IntegerAttribute::new(42, IntegerType::new(&context, 64).into());
+
+        assert_eq!(
+            attribute.value_signed(),
+            Err(Error::TypeExpected("signed integer", "i64".into()))
+        );
+        assert_eq!(
+            attribute.value_unsigned(),
+            Err(Error::TypeExpected("unsigned integer", "i64".into()))
+        );
+        assert_eq!(
+            IntegerAttribute::new(42, IntegerType::signed(&context, 64).into()).value_i64(),
+            Err(Error::TypeExpected("signless integer", "si64".into()))
+        );
+    }
+
+    #[test]
+    fn r#type() {
+        let context = Context::new();
+        let r#type = IntegerType::new(&context, 16).into();
+
+        assert_eq!(IntegerAttribute::new(42, r#type).r#type(), r#type);
+    }
+}
@@ -1,6 +1,9 @@
 use super::{Attribute, AttributeLike};
-use crate::{Context, Error, StringRef};
-use mlir_sys::{mlirStringAttrGet, MlirAttribute};
+use crate::{
+    ir::{Type, TypeLike},
+    Context, Error, StringRef,
+};
+use mlir_sys::{mlirStringAttrGet, mlirStringAttrGetValue, mlirStringAttrTypedGet, MlirAttribute};
 
 /// A string attribute.
 #[derive(Clone, Copy)]
@@ -18,6 +21,48 @@ impl<'c> StringAttribute<'c> {
             ))
         }
     }
+
+    /// Creates a string attribute with a type.
+    pub fn with_type(r#type: Type<'c>, string: &str) -> Self {
+        unsafe {
+            Self::from_raw(mlirStringAttrTypedGet(
+                r#type.to_raw(),
+                StringRef::new(string).to_raw(),
+            ))
+        }
+    }
+
+    /// Gets a value.
+    pub fn value(&self) -> StringRef<'c> {
+        unsafe { StringRef::from_raw(mlirStringAttrGetValue(self.to_raw())) }
+    }
 }
 
 attribute_traits!(StringAttribute, is_string, "string");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::r#type::IntegerType;
+
+    #[test]
+    fn value() {
+        let context = Context::new();
+
+        assert_eq!(
+            StringAttribute::new(&context, "foo").value().as_str(),
+            Ok("foo")
+        );
+    }
+
+    #[test]
+    fn with_type() {
+        let context = Context::new();
+        let r#type = IntegerType::new(&context, 8).into();
+        let attribute = StringAttribute::with_type(r#type, "foo");
+
+        assert_eq!(attribute.value().as_str(), Ok("foo"));
+        assert_eq!(attribute.r#type(), r#type);
+        assert_eq!(attribute.to_string(), "\"foo\" : i8");
+    }
+}
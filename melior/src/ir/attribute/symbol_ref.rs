@@ -0,0 +1,137 @@
+use super::{Attribute, AttributeLike, FlatSymbolRefAttribute};
+use crate::{Context, Error, StringRef};
+use mlir_sys::{
+    mlirSymbolRefAttrGet, mlirSymbolRefAttrGetLeafReference, mlirSymbolRefAttrGetNestedReference,
+    mlirSymbolRefAttrGetNumNestedReferences, mlirSymbolRefAttrGetRootReference, MlirAttribute,
+};
+
+/// A symbol ref attribute.
+#[derive(Clone, Copy)]
+pub struct SymbolRefAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> SymbolRefAttribute<'c> {
+    /// Creates a symbol ref attribute.
+    pub fn new(context: &'c Context, symbol: &str, nested: &[FlatSymbolRefAttribute<'c>]) -> Self {
+        unsafe {
+            Self::from_raw(mlirSymbolRefAttrGet(
+                context.to_raw(),
+                StringRef::new(symbol).to_raw(),
+                nested.len() as isize,
+                nested as *const _ as *const _,
+            ))
+        }
+    }
+
+    /// Gets a root reference.
+    pub fn root_reference(&self) -> StringRef<'c> {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetRootReference(self.to_raw())) }
+    }
+
+    /// Gets a leaf reference.
+    ///
+    /// It is the same as a root reference if there is no nested reference.
+    pub fn leaf_reference(&self) -> StringRef<'c> {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetLeafReference(self.to_raw())) }
+    }
+
+    /// Gets a number of nested references.
+    pub fn nested_reference_count(&self) -> usize {
+        unsafe { mlirSymbolRefAttrGetNumNestedReferences(self.to_raw()) as usize }
+    }
+
+    /// Gets a nested reference.
+    pub fn nested_reference(&self, index: usize) -> Result<FlatSymbolRefAttribute<'c>, Error> {
+        if index < self.nested_reference_count() {
+            unsafe {
+                Attribute::from_raw(mlirSymbolRefAttrGetNestedReference(
+                    self.to_raw(),
+                    index as isize,
+                ))
+            }
+            .try_into()
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "nested reference",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Gets nested references.
+    pub fn nested_references(
+        &self,
+    ) -> impl ExactSizeIterator<Item = FlatSymbolRefAttribute<'c>> + '_ {
+        (0..self.nested_reference_count()).map(|index| {
+            self.nested_reference(index)
+                .expect("valid nested reference index")
+        })
+    }
+}
+
+attribute_traits!(SymbolRefAttribute, is_symbol_ref, "symbol ref");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Attribute::from(SymbolRefAttribute::new(
+                &context,
+                "foo",
+                &[
+                    FlatSymbolRefAttribute::new(&context, "bar"),
+                    FlatSymbolRefAttribute::new(&context, "baz"),
+                ],
+            )),
+            Attribute::parse(&context, "@foo::@bar::@baz").unwrap()
+        );
+    }
+
+    #[test]
+    fn references() {
+        let context = Context::new();
+        let attribute = SymbolRefAttribute::new(
+            &context,
+            "foo",
+            &[
+                FlatSymbolRefAttribute::new(&context, "bar"),
+                FlatSymbolRefAttribute::new(&context, "baz"),
+            ],
+        );
+
+        assert_eq!(attribute.root_reference().as_str(), Ok("foo"));
+        assert_eq!(attribute.leaf_reference().as_str(), Ok("baz"));
+        assert_eq!(attribute.nested_reference_count(), 2);
+        assert_eq!(
+            attribute
+                .nested_references()
+                .map(|reference| reference.value().to_owned())
+                .collect::<Vec<_>>(),
+            vec!["bar", "baz"]
+        );
+        assert_eq!(
+            attribute.nested_reference(2).unwrap_err(),
+            Error::PositionOutOfBounds {
+                name: "nested reference",
+                value: attribute.to_string(),
+                index: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn without_nested_references() {
+        let context = Context::new();
+        let attribute = SymbolRefAttribute::new(&context, "foo", &[]);
+
+        assert_eq!(attribute.leaf_reference().as_str(), Ok("foo"));
+        assert_eq!(attribute.nested_references().count(), 0);
+    }
+}
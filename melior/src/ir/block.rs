@@ -7,15 +7,16 @@ use super::{Location, Operation, OperationRef, RegionRef, Type, TypeLike, Value}
 use crate::{context::Context, utility::print_callback, Error};
 use mlir_sys::{
     mlirBlockAddArgument, mlirBlockAppendOwnedOperation, mlirBlockCreate, mlirBlockDestroy,
-    mlirBlockDetach, mlirBlockEqual, mlirBlockGetArgument, mlirBlockGetFirstOperation,
-    mlirBlockGetNextInRegion, mlirBlockGetNumArguments, mlirBlockGetParentOperation,
-    mlirBlockGetParentRegion, mlirBlockGetTerminator, mlirBlockInsertOwnedOperation,
-    mlirBlockInsertOwnedOperationAfter, mlirBlockInsertOwnedOperationBefore, mlirBlockPrint,
-    MlirBlock,
+    mlirBlockDetach, mlirBlockEqual, mlirBlockEraseArgument, mlirBlockGetArgument,
+    mlirBlockGetFirstOperation, mlirBlockGetNextInRegion, mlirBlockGetNumArguments,
+    mlirBlockGetParentOperation, mlirBlockGetParentRegion, mlirBlockGetTerminator,
+    mlirBlockInsertArgument, mlirBlockInsertOwnedOperation, mlirBlockInsertOwnedOperationAfter,
+    mlirBlockInsertOwnedOperationBefore, mlirBlockPrint, mlirOperationGetNextInBlock, MlirBlock,
 };
 use std::{
     ffi::c_void,
     fmt::{self, Debug, Display, Formatter},
+    iter::successors,
     marker::PhantomData,
     mem::{forget, transmute},
     ops::Deref,
@@ -71,6 +72,11 @@ impl<'c> Block<'c> {
         unsafe { mlirBlockGetNumArguments(self.raw) as usize }
     }
 
+    /// Gets all arguments.
+    pub fn arguments(&self) -> impl ExactSizeIterator<Item = BlockArgument<'c, '_>> {
+        (0..self.argument_count()).map(|index| self.argument(index).expect("valid argument index"))
+    }
+
     /// Gets the first operation.
     pub fn first_operation(&self) -> Option<OperationRef> {
         unsafe {
@@ -84,12 +90,27 @@ impl<'c> Block<'c> {
         }
     }
 
+    /// Returns an iterator over operations in a block.
+    pub fn iter(&self) -> impl Iterator<Item = OperationRef<'c, '_>> {
+        successors(
+            unsafe { OperationRef::from_option_raw(mlirBlockGetFirstOperation(self.raw)) },
+            |operation| unsafe {
+                OperationRef::from_option_raw(mlirOperationGetNextInBlock(operation.to_raw()))
+            },
+        )
+    }
+
     /// Gets a terminator operation.
+    ///
+    /// It returns `None` if a block is empty or its last operation is not a
+    /// terminator.
     pub fn terminator(&self) -> Option<OperationRef> {
         unsafe { OperationRef::from_option_raw(mlirBlockGetTerminator(self.raw)) }
     }
 
     /// Gets a parent region.
+    ///
+    /// It returns `None` if a block is not inserted into any region.
     // TODO Store lifetime of regions in blocks, or create another type like
     // `InsertedBlockRef`?
     pub fn parent_region(&self) -> Option<RegionRef<'c, '_>> {
@@ -97,7 +118,10 @@ impl<'c> Block<'c> {
     }
 
     /// Gets a parent operation.
-    pub fn parent_operation(&self) -> Option<OperationRef> {
+    ///
+    /// It returns `None` if a block is not inserted into any region or its
+    /// region does not belong to any operation.
+    pub fn parent_operation(&self) -> Option<OperationRef<'c, '_>> {
         unsafe { OperationRef::from_option_raw(mlirBlockGetParentOperation(self.raw)) }
     }
 
@@ -112,6 +136,51 @@ impl<'c> Block<'c> {
         }
     }
 
+    /// Inserts an argument at a position.
+    pub fn insert_argument(
+        &self,
+        index: usize,
+        r#type: Type<'c>,
+        location: Location<'c>,
+    ) -> Result<BlockArgument<'c, '_>, Error> {
+        if index <= self.argument_count() {
+            Ok(unsafe {
+                BlockArgument::from_raw(mlirBlockInsertArgument(
+                    self.raw,
+                    index as isize,
+                    r#type.to_raw(),
+                    location.to_raw(),
+                ))
+            })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "block argument",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Erases an argument at a position.
+    ///
+    /// It returns an error if the argument has any uses.
+    ///
+    /// # Safety
+    ///
+    /// The argument must not be used anymore by any references, such as
+    /// block arguments and values obtained from the block.
+    pub unsafe fn erase_argument(&self, index: usize) -> Result<(), Error> {
+        let argument = self.argument(index)?;
+
+        if Value::from(argument).use_count() > 0 {
+            return Err(Error::ValueInUse(argument.to_string()));
+        }
+
+        mlirBlockEraseArgument(self.raw, index as _);
+
+        Ok(())
+    }
+
     /// Appends an operation.
     pub fn append_operation(&self, operation: Operation<'c>) -> OperationRef<'c, '_> {
         unsafe {
@@ -143,7 +212,7 @@ impl<'c> Block<'c> {
     /// Inserts an operation after another.
     pub fn insert_operation_after(
         &self,
-        one: OperationRef,
+        one: OperationRef<'c, '_>,
         other: Operation<'c>,
     ) -> OperationRef<'c, '_> {
         unsafe {
@@ -172,6 +241,12 @@ impl<'c> Block<'c> {
 
     /// Detaches a block from a region and assumes its ownership.
     ///
+    /// The returned block is dropped with its operations unless it is moved
+    /// into another region by
+    /// [`Region::append_block`](crate::ir::Region::append_block) or other
+    /// insertion methods, which take its ownership back. It returns `None` if
+    /// a block does not belong to any region.
+    ///
     /// # Safety
     ///
     /// This function might invalidate existing references to the block if you
@@ -323,7 +398,10 @@ impl<'c, 'a> Debug for BlockRef<'c, 'a> {
 mod tests {
     use super::*;
     use crate::{
-        ir::{operation::OperationBuilder, r#type::IntegerType, Module, Region, ValueLike},
+        ir::{
+            operation::OperationBuilder, r#type::IntegerType, Attribute, Identifier, Module,
+            Region, ValueLike,
+        },
         test::create_test_context,
     };
     use pretty_assertions::assert_eq;
@@ -347,6 +425,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arguments() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let integer_type = IntegerType::new(&context, 64).into();
+        let block = Block::new(&[(index_type, location), (integer_type, location)]);
+
+        assert_eq!(block.arguments().len(), 2);
+        assert_eq!(
+            block
+                .arguments()
+                .map(|argument| argument.r#type())
+                .collect::<Vec<_>>(),
+            vec![index_type, integer_type]
+        );
+        assert_eq!(
+            block
+                .arguments()
+                .map(|argument| argument.argument_number())
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+    }
+
     #[test]
     fn argument_error() {
         assert_eq!(
@@ -359,6 +462,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn insert_argument() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let integer_type = IntegerType::new(&context, 64).into();
+        let block = Block::new(&[(index_type, location), (index_type, location)]);
+
+        assert_eq!(
+            block
+                .insert_argument(1, integer_type, location)
+                .unwrap()
+                .r#type(),
+            integer_type
+        );
+        assert_eq!(block.argument_count(), 3);
+        assert_eq!(block.argument(0).unwrap().r#type(), index_type);
+        assert_eq!(block.argument(1).unwrap().r#type(), integer_type);
+        assert_eq!(block.argument(2).unwrap().r#type(), index_type);
+    }
+
+    #[test]
+    fn insert_argument_error() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Block::new(&[])
+                .insert_argument(1, Type::index(&context), Location::unknown(&context))
+                .unwrap_err(),
+            Error::PositionOutOfBounds {
+                name: "block argument",
+                value: "<<UNLINKED BLOCK>>\n".into(),
+                index: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn erase_argument() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let integer_type = IntegerType::new(&context, 64).into();
+        let block = Block::new(&[(index_type, location), (integer_type, location)]);
+
+        unsafe { block.erase_argument(0) }.unwrap();
+
+        assert_eq!(block.argument_count(), 1);
+        assert_eq!(block.argument(0).unwrap().r#type(), integer_type);
+    }
+
+    #[test]
+    fn erase_argument_error() {
+        assert_eq!(
+            unsafe { Block::new(&[]).erase_argument(0) },
+            Err(Error::PositionOutOfBounds {
+                name: "block argument",
+                value: "<<UNLINKED BLOCK>>\n".into(),
+                index: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn erase_argument_in_use() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let block = Block::new(&[(Type::index(&context), location)]);
+
+        block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[block.argument(0).unwrap().into()])
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            unsafe { block.erase_argument(0) },
+            Err(Error::ValueInUse(block.argument(0).unwrap().to_string()))
+        );
+        assert_eq!(block.argument_count(), 1);
+    }
+
     #[test]
     fn argument_count() {
         assert_eq!(Block::new(&[]).argument_count(), 0);
@@ -397,6 +584,15 @@ mod tests {
         assert_eq!(block.parent_operation(), None);
     }
 
+    #[test]
+    fn parent_operation_of_detached_region() {
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+
+        assert!(block.parent_region().is_some());
+        assert_eq!(block.parent_operation(), None);
+    }
+
     #[test]
     fn terminator() {
         let context = create_test_context();
@@ -417,6 +613,26 @@ mod tests {
         assert_eq!(Block::new(&[]).terminator(), None);
     }
 
+    #[test]
+    fn terminator_none_with_non_terminator() {
+        let context = create_test_context();
+
+        let block = Block::new(&[]);
+
+        block.append_operation(
+            OperationBuilder::new("arith.constant", Location::unknown(&context))
+                .add_attributes(&[(
+                    Identifier::new(&context, "value"),
+                    Attribute::parse(&context, "0 : index").unwrap(),
+                )])
+                .add_results(&[Type::index(&context)])
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(block.terminator(), None);
+    }
+
     #[test]
     fn first_operation() {
         let context = create_test_context();
@@ -439,6 +655,31 @@ mod tests {
         assert_eq!(block.first_operation(), None);
     }
 
+    #[test]
+    fn iter() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let block = Block::new(&[]);
+
+        assert_eq!(block.iter().count(), 0);
+
+        let first_operation = block.append_operation(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .build()
+                .unwrap(),
+        );
+        let second_operation = block.append_operation(
+            OperationBuilder::new("bar", Location::unknown(&context))
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            block.iter().collect::<Vec<_>>(),
+            vec![first_operation, second_operation]
+        );
+    }
+
     #[test]
     fn append_operation() {
         let context = create_test_context();
@@ -537,6 +778,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detach_and_move() {
+        let region = Region::new();
+        let other_region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+
+        let block = other_region.append_block(unsafe { block.detach() }.unwrap());
+
+        assert!(region.first_block().is_none());
+        assert_eq!(other_region.first_block(), Some(block));
+    }
+
     #[test]
     fn detach_detached() {
         let block = Block::new(&[]);
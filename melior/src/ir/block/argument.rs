@@ -20,13 +20,13 @@ impl<'c, 'a> BlockArgument<'c, 'a> {
         unsafe { mlirBlockArgumentGetArgNumber(self.value.to_raw()) as usize }
     }
 
-    /// Gets an owner operation.
-    pub fn owner(&self) -> BlockRef<'c, '_> {
+    /// Gets an owner block.
+    pub fn owner(&self) -> BlockRef<'c, 'a> {
         unsafe { BlockRef::from_raw(mlirBlockArgumentGetOwner(self.value.to_raw())) }
     }
 
     /// Sets a type.
-    pub fn set_type(&self, r#type: Type) {
+    pub fn set_type(&self, r#type: Type<'c>) {
         unsafe { mlirBlockArgumentSetType(self.value.to_raw(), r#type.to_raw()) }
     }
 
@@ -92,6 +92,16 @@ mod tests {
         assert_eq!(&*block.argument(0).unwrap().owner(), &block);
     }
 
+    #[test]
+    fn owner_outlives_argument() {
+        let context = Context::new();
+        let r#type = Type::parse(&context, "index").unwrap();
+        let block = Block::new(&[(r#type, Location::unknown(&context))]);
+        let owner = block.argument(0).unwrap().owner();
+
+        assert_eq!(owner.argument_count(), 1);
+    }
+
     #[test]
     fn set_type() {
         let context = Context::new();
@@ -0,0 +1,239 @@
+//! IR builders.
+
+use super::{BlockRef, Operation, OperationRef};
+use crate::context::Context;
+use mlir_sys::{
+    mlirBlockAppendOwnedOperation, mlirBlockGetFirstOperation, mlirBlockInsertOwnedOperationBefore,
+    mlirOperationGetBlock, mlirOperationGetNextInBlock,
+};
+
+/// An IR builder.
+///
+/// It inserts operations at its insertion point in a block like `OpBuilder`
+/// in MLIR. Operations created one after another are placed in the same
+/// order.
+#[derive(Clone, Copy, Debug)]
+pub struct Builder<'c, 'a> {
+    context: &'c Context,
+    block: BlockRef<'c, 'a>,
+    // An operation before which new operations are inserted. `None` means the
+    // end of a block.
+    next: Option<OperationRef<'c, 'a>>,
+}
+
+impl<'c, 'a> Builder<'c, 'a> {
+    /// Creates a builder inserting operations at the start of a block.
+    pub fn at_start(context: &'c Context, block: BlockRef<'c, 'a>) -> Self {
+        Self {
+            context,
+            block,
+            next: unsafe {
+                OperationRef::from_option_raw(mlirBlockGetFirstOperation(block.to_raw()))
+            },
+        }
+    }
+
+    /// Creates a builder inserting operations at the end of a block.
+    pub fn at_end(context: &'c Context, block: BlockRef<'c, 'a>) -> Self {
+        Self {
+            context,
+            block,
+            next: None,
+        }
+    }
+
+    /// Creates a builder inserting operations after an operation.
+    ///
+    /// It returns `None` if the operation does not belong to any block.
+    pub fn after(context: &'c Context, operation: OperationRef<'c, 'a>) -> Option<Self> {
+        let mut builder = Self::at_end(context, Self::operation_block(operation)?);
+
+        builder.set_insertion_point_after(operation);
+        Some(builder)
+    }
+
+    /// Gets a context.
+    pub fn context(&self) -> &'c Context {
+        self.context
+    }
+
+    /// Gets a block of an insertion point.
+    pub fn insertion_block(&self) -> BlockRef<'c, 'a> {
+        self.block
+    }
+
+    /// Sets an insertion point to the start of a block.
+    pub fn set_insertion_point_to_start(&mut self, block: BlockRef<'c, 'a>) {
+        *self = Self::at_start(self.context, block);
+    }
+
+    /// Sets an insertion point to the end of a block.
+    pub fn set_insertion_point_to_end(&mut self, block: BlockRef<'c, 'a>) {
+        *self = Self::at_end(self.context, block);
+    }
+
+    /// Sets an insertion point before an operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operation does not belong to any block.
+    pub fn set_insertion_point_before(&mut self, operation: OperationRef<'c, 'a>) {
+        self.block = Self::operation_block(operation).expect("operation in block");
+        self.next = Some(operation);
+    }
+
+    /// Sets an insertion point after an operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operation does not belong to any block.
+    pub fn set_insertion_point_after(&mut self, operation: OperationRef<'c, 'a>) {
+        self.block = Self::operation_block(operation).expect("operation in block");
+        self.next = unsafe {
+            OperationRef::from_option_raw(mlirOperationGetNextInBlock(operation.to_raw()))
+        };
+    }
+
+    /// Inserts an operation at an insertion point.
+    pub fn create(&self, operation: Operation<'c>) -> OperationRef<'c, 'a> {
+        unsafe {
+            let operation = operation.into_raw();
+
+            if let Some(next) = self.next {
+                mlirBlockInsertOwnedOperationBefore(self.block.to_raw(), next.to_raw(), operation);
+            } else {
+                mlirBlockAppendOwnedOperation(self.block.to_raw(), operation);
+            }
+
+            OperationRef::from_raw(operation)
+        }
+    }
+
+    fn operation_block(operation: OperationRef<'c, 'a>) -> Option<BlockRef<'c, 'a>> {
+        unsafe { BlockRef::from_option_raw(mlirOperationGetBlock(operation.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{operation::OperationBuilder, Block, Location, Module, Region},
+        test::create_test_context,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn create_operation<'c>(context: &'c Context, name: &str) -> Operation<'c> {
+        OperationBuilder::new(name, Location::unknown(context))
+            .build()
+            .unwrap()
+    }
+
+    fn operation_names(block: &Block) -> Vec<String> {
+        block
+            .iter()
+            .map(|operation| {
+                operation
+                    .name()
+                    .as_string_ref()
+                    .as_str()
+                    .unwrap()
+                    .to_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn at_end() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        block.append_operation(create_operation(&context, "foo"));
+
+        let builder = Builder::at_end(&context, block);
+        builder.create(create_operation(&context, "bar"));
+        builder.create(create_operation(&context, "baz"));
+
+        assert_eq!(operation_names(&block), ["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn at_start() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        block.append_operation(create_operation(&context, "foo"));
+
+        let builder = Builder::at_start(&context, block);
+        builder.create(create_operation(&context, "bar"));
+        builder.create(create_operation(&context, "baz"));
+
+        assert_eq!(operation_names(&block), ["bar", "baz", "foo"]);
+    }
+
+    #[test]
+    fn at_start_of_empty_block() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+
+        let builder = Builder::at_start(&context, block);
+        builder.create(create_operation(&context, "foo"));
+
+        assert_eq!(operation_names(&block), ["foo"]);
+    }
+
+    #[test]
+    fn after() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        let operation = block.append_operation(create_operation(&context, "foo"));
+        block.append_operation(create_operation(&context, "qux"));
+
+        let builder = Builder::after(&context, operation).unwrap();
+        builder.create(create_operation(&context, "bar"));
+        builder.create(create_operation(&context, "baz"));
+
+        assert_eq!(operation_names(&block), ["foo", "bar", "baz", "qux"]);
+    }
+
+    #[test]
+    fn set_insertion_point() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        let other_block = region.append_block(Block::new(&[]));
+
+        let mut builder = Builder::at_end(&context, block);
+        let operation = builder.create(create_operation(&context, "foo"));
+
+        builder.set_insertion_point_to_end(other_block);
+        builder.create(create_operation(&context, "bar"));
+
+        assert_eq!(builder.insertion_block(), other_block);
+
+        builder.set_insertion_point_before(operation);
+        builder.create(create_operation(&context, "baz"));
+
+        builder.set_insertion_point_to_start(other_block);
+        builder.create(create_operation(&context, "qux"));
+
+        assert_eq!(operation_names(&block), ["baz", "foo"]);
+        assert_eq!(operation_names(&other_block), ["qux", "bar"]);
+    }
+
+    #[test]
+    fn after_operation_without_block() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let module = Module::new(Location::unknown(&context));
+
+        assert!(Builder::after(&context, module.as_operation()).is_none());
+    }
+}
@@ -0,0 +1,280 @@
+use super::AffineExpr;
+use crate::{
+    context::{Context, ContextRef},
+    utility::print_callback,
+    Error,
+};
+use mlir_sys::{
+    mlirIntegerSetDump, mlirIntegerSetEmptyGet, mlirIntegerSetEqual, mlirIntegerSetGet,
+    mlirIntegerSetGetConstraint, mlirIntegerSetGetContext, mlirIntegerSetGetNumConstraints,
+    mlirIntegerSetGetNumDims, mlirIntegerSetGetNumEqualities, mlirIntegerSetGetNumInequalities,
+    mlirIntegerSetGetNumSymbols, mlirIntegerSetIsCanonicalEmpty, mlirIntegerSetIsConstraintEq,
+    mlirIntegerSetPrint, MlirIntegerSet,
+};
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+/// An integer set.
+#[derive(Clone, Copy)]
+pub struct IntegerSet<'c> {
+    raw: MlirIntegerSet,
+    _context: PhantomData<&'c Context>,
+}
+
+impl<'c> IntegerSet<'c> {
+    /// Creates an integer set.
+    ///
+    /// Each constraint is an equality `expression == 0` if its flag is `true`,
+    /// or an inequality `expression >= 0` otherwise.
+    pub fn new(
+        context: &'c Context,
+        dimension_count: usize,
+        symbol_count: usize,
+        constraints: &[AffineExpr<'c>],
+        equality_flags: &[bool],
+    ) -> Result<Self, Error> {
+        if constraints.len() != equality_flags.len() {
+            return Err(Error::ArgumentLengthMismatch {
+                name: "equality_flags",
+                expected: constraints.len(),
+                actual: equality_flags.len(),
+            });
+        }
+
+        Ok(unsafe {
+            Self::from_raw(mlirIntegerSetGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+                constraints.len() as isize,
+                constraints
+                    .iter()
+                    .map(|constraint| constraint.to_raw())
+                    .collect::<Vec<_>>()
+                    .as_ptr(),
+                equality_flags.as_ptr(),
+            ))
+        })
+    }
+
+    /// Creates an empty integer set.
+    pub fn empty(context: &'c Context, dimension_count: usize, symbol_count: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirIntegerSetEmptyGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+            ))
+        }
+    }
+
+    /// Gets a number of dimensions.
+    pub fn dimension_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumDims(self.raw) as usize }
+    }
+
+    /// Gets a number of symbols.
+    pub fn symbol_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumSymbols(self.raw) as usize }
+    }
+
+    /// Gets a number of constraints.
+    pub fn constraint_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumConstraints(self.raw) as usize }
+    }
+
+    /// Gets a number of equality constraints.
+    pub fn equality_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumEqualities(self.raw) as usize }
+    }
+
+    /// Gets a number of inequality constraints.
+    pub fn inequality_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumInequalities(self.raw) as usize }
+    }
+
+    /// Gets a constraint at a position.
+    pub fn constraint(&self, index: usize) -> Result<AffineExpr<'c>, Error> {
+        if index < self.constraint_count() {
+            Ok(unsafe {
+                AffineExpr::from_raw(mlirIntegerSetGetConstraint(self.raw, index as isize))
+            })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "integer set constraint",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Checks if a constraint at a position is an equality.
+    pub fn is_constraint_eq(&self, index: usize) -> Result<bool, Error> {
+        if index < self.constraint_count() {
+            Ok(unsafe { mlirIntegerSetIsConstraintEq(self.raw, index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "integer set constraint",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Checks if an integer set is a canonical empty set.
+    pub fn is_canonical_empty(&self) -> bool {
+        unsafe { mlirIntegerSetIsCanonicalEmpty(self.raw) }
+    }
+
+    /// Gets a context.
+    pub fn context(&self) -> ContextRef<'c> {
+        unsafe { ContextRef::from_raw(mlirIntegerSetGetContext(self.raw)) }
+    }
+
+    /// Dumps an integer set.
+    pub fn dump(&self) {
+        unsafe { mlirIntegerSetDump(self.raw) }
+    }
+
+    /// Creates an integer set from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirIntegerSet) -> Self {
+        Self {
+            raw,
+            _context: Default::default(),
+        }
+    }
+
+    /// Converts an integer set into a raw object.
+    pub const fn to_raw(self) -> MlirIntegerSet {
+        self.raw
+    }
+}
+
+impl<'c> PartialEq for IntegerSet<'c> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirIntegerSetEqual(self.raw, other.raw) }
+    }
+}
+
+impl<'c> Eq for IntegerSet<'c> {}
+
+impl<'c> Display for IntegerSet<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let mut data = (formatter, Ok(()));
+
+        unsafe {
+            mlirIntegerSetPrint(
+                self.raw,
+                Some(print_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1
+    }
+}
+
+impl<'c> Debug for IntegerSet<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn create_set(context: &Context) -> IntegerSet {
+        IntegerSet::new(
+            context,
+            1,
+            1,
+            &[
+                AffineExpr::dimension(context, 0),
+                AffineExpr::symbol(context, 0),
+            ],
+            &[false, true],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            create_set(&context).to_string(),
+            "(d0)[s0] : (d0 >= 0, s0 == 0)"
+        );
+    }
+
+    #[test]
+    fn new_with_flag_count_mismatch() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerSet::new(&context, 1, 0, &[AffineExpr::dimension(&context, 0)], &[]),
+            Err(Error::ArgumentLengthMismatch {
+                name: "equality_flags",
+                expected: 1,
+                actual: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let context = Context::new();
+        let set = IntegerSet::empty(&context, 1, 0);
+
+        assert!(set.is_canonical_empty());
+        assert_eq!(set.dimension_count(), 1);
+        assert_eq!(set.symbol_count(), 0);
+    }
+
+    #[test]
+    fn counts() {
+        let context = Context::new();
+        let set = create_set(&context);
+
+        assert_eq!(set.dimension_count(), 1);
+        assert_eq!(set.symbol_count(), 1);
+        assert_eq!(set.constraint_count(), 2);
+        assert_eq!(set.equality_count(), 1);
+        assert_eq!(set.inequality_count(), 1);
+    }
+
+    #[test]
+    fn constraint() {
+        let context = Context::new();
+        let set = create_set(&context);
+
+        assert_eq!(set.constraint(0), Ok(AffineExpr::dimension(&context, 0)));
+        assert_eq!(set.is_constraint_eq(0), Ok(false));
+        assert_eq!(set.is_constraint_eq(1), Ok(true));
+        assert_eq!(
+            set.constraint(2),
+            Err(Error::PositionOutOfBounds {
+                name: "integer set constraint",
+                value: set.to_string(),
+                index: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn equal() {
+        let context = Context::new();
+
+        assert_eq!(create_set(&context), create_set(&context));
+        assert_ne!(create_set(&context), IntegerSet::empty(&context, 1, 1));
+    }
+}
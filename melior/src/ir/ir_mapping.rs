@@ -0,0 +1,86 @@
+use super::{Value, ValueLike};
+use std::{collections::HashMap, ffi::c_void};
+
+/// A mapping of values.
+///
+/// It is used to replace values referenced by cloned operations. See
+/// [`Operation::clone_with_mapping`](crate::ir::Operation::clone_with_mapping).
+///
+/// Note that the MLIR C API does not expose `IRMapping` yet. Therefore, this
+/// is implemented in Rust and only values but not blocks are mapped.
+#[derive(Clone, Debug, Default)]
+pub struct IrMapping<'c, 'a> {
+    values: HashMap<*const c_void, Value<'c, 'a>>,
+}
+
+impl<'c, 'a> IrMapping<'c, 'a> {
+    /// Creates a mapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps a value to another.
+    pub fn map(&mut self, from: impl ValueLike<'c>, to: impl Into<Value<'c, 'a>>) {
+        self.values.insert(from.to_raw().ptr, to.into());
+    }
+
+    /// Looks up a value mapped from a value.
+    pub fn lookup(&self, value: impl ValueLike<'c>) -> Option<Value<'c, 'a>> {
+        self.values.get(&value.to_raw().ptr).copied()
+    }
+
+    /// Checks if a value is mapped.
+    pub fn contains(&self, value: impl ValueLike<'c>) -> bool {
+        self.values.contains_key(&value.to_raw().ptr)
+    }
+
+    /// Removes a mapping of a value.
+    pub fn erase(&mut self, value: impl ValueLike<'c>) {
+        self.values.remove(&value.to_raw().ptr);
+    }
+
+    /// Gets a number of mapped values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Checks if a mapping is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Block, Location, Type},
+        Context,
+    };
+
+    #[test]
+    fn map() {
+        let context = Context::new();
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first = block.argument(0).unwrap();
+        let second = block.argument(1).unwrap();
+
+        let mut mapping = IrMapping::new();
+
+        assert!(mapping.is_empty());
+        assert_eq!(mapping.lookup(first), None);
+
+        mapping.map(first, second);
+
+        assert_eq!(mapping.len(), 1);
+        assert!(mapping.contains(first));
+        assert!(!mapping.contains(second));
+        assert_eq!(mapping.lookup(first), Some(second.into()));
+
+        mapping.erase(first);
+
+        assert!(!mapping.contains(first));
+    }
+}
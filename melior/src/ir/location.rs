@@ -36,7 +36,7 @@ impl<'c> Location<'c> {
     }
 
     /// Creates a fused location.
-    pub fn fused(context: &'c Context, locations: &[Self], attribute: Attribute) -> Self {
+    pub fn fused(context: &'c Context, locations: &[Self], attribute: Attribute<'c>) -> Self {
         unsafe {
             Self::from_raw(mlirLocationFusedGet(
                 context.to_raw(),
@@ -48,18 +48,20 @@ impl<'c> Location<'c> {
     }
 
     /// Creates a name location.
-    pub fn name(context: &'c Context, name: &str, child: Location) -> Self {
+    ///
+    /// A child location defaults to an unknown location.
+    pub fn name(context: &'c Context, name: &str, child: Option<Self>) -> Self {
         unsafe {
             Self::from_raw(mlirLocationNameGet(
                 context.to_raw(),
                 StringRef::new(name).to_raw(),
-                child.to_raw(),
+                child.unwrap_or_else(|| Self::unknown(context)).to_raw(),
             ))
         }
     }
 
     /// Creates a call site location.
-    pub fn call_site(callee: Location, caller: Location) -> Self {
+    pub fn call_site(callee: Self, caller: Self) -> Self {
         unsafe { Self::from_raw(mlirLocationCallSiteGet(callee.to_raw(), caller.to_raw())) }
     }
 
@@ -73,6 +75,27 @@ impl<'c> Location<'c> {
         unsafe { ContextRef::from_raw(mlirLocationGetContext(self.raw)) }
     }
 
+    /// Gets a filename of a file-line-column location.
+    ///
+    /// It returns `None` if a location is not a file-line-column location.
+    pub fn filename(&self) -> Option<String> {
+        self.file_line_column().map(|(filename, _, _)| filename)
+    }
+
+    /// Gets a line number of a file-line-column location.
+    ///
+    /// It returns `None` if a location is not a file-line-column location.
+    pub fn line(&self) -> Option<usize> {
+        self.file_line_column().map(|(_, line, _)| line)
+    }
+
+    /// Gets a column number of a file-line-column location.
+    ///
+    /// It returns `None` if a location is not a file-line-column location.
+    pub fn column(&self) -> Option<usize> {
+        self.file_line_column().map(|(_, _, column)| column)
+    }
+
     /// Creates a location from a raw object.
     ///
     /// # Safety
@@ -89,6 +112,45 @@ impl<'c> Location<'c> {
     pub const fn to_raw(self) -> MlirLocation {
         self.raw
     }
+
+    // The MLIR C API does not expose accessors of file-line-column locations
+    // yet. So we read them back from their textual representations of
+    // `loc("filename":line:column)` instead.
+    fn file_line_column(&self) -> Option<(String, usize, usize)> {
+        let string = self.to_string();
+        let string = string.strip_prefix("loc(\"")?.strip_suffix(')')?;
+        let (string, column) = string.rsplit_once(':')?;
+        let (filename, line) = string.rsplit_once(':')?;
+
+        Some((
+            unescape(filename.strip_suffix('"')?)?,
+            line.parse().ok()?,
+            column.parse().ok()?,
+        ))
+    }
+}
+
+// Unescapes a string escaped by `llvm::printEscapedString`.
+fn unescape(string: &str) -> Option<String> {
+    let mut bytes = vec![];
+    let mut rest = string.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+
+        if byte != b'\\' {
+            bytes.push(byte);
+        } else if let Some((&b'\\', tail)) = rest.split_first() {
+            bytes.push(b'\\');
+            rest = tail;
+        } else {
+            let digits = rest.get(..2)?;
+            bytes.push(u8::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()?);
+            rest = &rest[2..];
+        }
+    }
+
+    String::from_utf8(bytes).ok()
 }
 
 impl<'c> PartialEq for Location<'c> {
@@ -127,13 +189,17 @@ mod tests {
     fn fused() {
         let context = Context::new();
 
-        Location::fused(
-            &context,
-            &[
-                Location::new(&Context::new(), "foo", 1, 1),
-                Location::new(&Context::new(), "foo", 2, 2),
-            ],
-            Attribute::parse(&context, "42").unwrap(),
+        assert_eq!(
+            Location::fused(
+                &context,
+                &[
+                    Location::new(&context, "foo", 1, 1),
+                    Location::new(&context, "foo", 2, 2),
+                ],
+                Attribute::parse(&context, "42").unwrap(),
+            )
+            .to_string(),
+            "loc(fused<42 : i64>[\"foo\":1:1, \"foo\":2:2])"
         );
     }
 
@@ -141,7 +207,20 @@ mod tests {
     fn name() {
         let context = Context::new();
 
-        Location::name(&context, "foo", Location::unknown(&context));
+        assert_eq!(
+            Location::name(&context, "foo", None).to_string(),
+            "loc(\"foo\")"
+        );
+    }
+
+    #[test]
+    fn name_with_child() {
+        let context = Context::new();
+
+        assert_eq!(
+            Location::name(&context, "foo", Some(Location::new(&context, "bar", 1, 2))).to_string(),
+            "loc(\"foo\"(\"bar\":1:2))"
+        );
     }
 
     #[test]
@@ -151,6 +230,21 @@ mod tests {
         Location::call_site(Location::unknown(&context), Location::unknown(&context));
     }
 
+    #[test]
+    fn nested_call_site() {
+        let context = Context::new();
+        let callee = Location::new(&context, "foo", 1, 2);
+        let caller = Location::call_site(
+            Location::new(&context, "bar", 3, 4),
+            Location::new(&context, "baz", 5, 6),
+        );
+
+        assert_eq!(
+            Location::call_site(callee, caller).to_string(),
+            "loc(callsite(\"foo\":1:2 at callsite(\"bar\":3:4 at \"baz\":5:6)))"
+        );
+    }
+
     #[test]
     fn unknown() {
         Location::unknown(&Context::new());
@@ -161,6 +255,38 @@ mod tests {
         Location::new(&Context::new(), "foo", 42, 42).context();
     }
 
+    #[test]
+    fn file_line_column() {
+        let context = Context::new();
+        let location = Location::new(&context, "foo.mlir", 42, 13);
+
+        assert_eq!(location.filename(), Some("foo.mlir".into()));
+        assert_eq!(location.line(), Some(42));
+        assert_eq!(location.column(), Some(13));
+    }
+
+    #[test]
+    fn file_line_column_with_escaped_filename() {
+        let context = Context::new();
+
+        assert_eq!(
+            Location::new(&context, "foo\\\"bar\":1:2.mlir", 1, 2).filename(),
+            Some("foo\\\"bar\":1:2.mlir".into())
+        );
+    }
+
+    #[test]
+    fn file_line_column_with_other_locations() {
+        let context = Context::new();
+
+        assert_eq!(Location::unknown(&context).filename(), None);
+        assert_eq!(Location::name(&context, "foo", None).line(), None);
+        assert_eq!(
+            Location::name(&context, "foo", Some(Location::new(&context, "bar", 1, 2))).column(),
+            None
+        );
+    }
+
     #[test]
     fn equal() {
         let context = Context::new();
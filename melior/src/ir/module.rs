@@ -2,12 +2,15 @@ use super::{operation::OperationRefMut, BlockRef, Location, Operation, Operation
 use crate::{
     context::{Context, ContextRef},
     string_ref::StringRef,
+    utility::collect_diagnostics,
+    Error,
 };
 use mlir_sys::{
     mlirModuleCreateEmpty, mlirModuleCreateParse, mlirModuleDestroy, mlirModuleFromOperation,
-    mlirModuleGetBody, mlirModuleGetContext, mlirModuleGetOperation, MlirModule,
+    mlirModuleGetBody, mlirModuleGetContext, mlirModuleGetOperation, mlirOperationClone,
+    MlirModule,
 };
-use std::{ffi::CString, marker::PhantomData};
+use std::{ffi::CString, fs, marker::PhantomData, path::Path};
 
 /// A module.
 #[derive(Debug)]
@@ -32,6 +35,20 @@ impl<'c> Module<'c> {
         unsafe { Self::from_option_raw(mlirModuleCreateParse(context.to_raw(), source.to_raw())) }
     }
 
+    /// Parses a module from a file.
+    ///
+    /// Note that the MLIR C API does not accept source file names yet.
+    /// Therefore, locations in diagnostics do not contain the file name.
+    pub fn parse_file(context: &Context, path: &Path) -> Result<Self, Error> {
+        let source = fs::read_to_string(path).map_err(|error| Error::ReadFile {
+            path: path.display().to_string(),
+            message: error.to_string(),
+        })?;
+        let (module, diagnostics) = collect_diagnostics(context, || Self::parse(context, &source));
+
+        module.ok_or_else(|| Error::ParseModule(diagnostics.join("\n")))
+    }
+
     /// Converts a module into an operation.
     pub fn as_operation(&self) -> OperationRef<'c, '_> {
         unsafe { OperationRef::from_raw(mlirModuleGetOperation(self.raw)) }
@@ -52,6 +69,19 @@ impl<'c> Module<'c> {
         unsafe { BlockRef::from_raw(mlirModuleGetBody(self.raw)) }
     }
 
+    /// Verifies a module.
+    ///
+    /// Verification errors are emitted as diagnostics to a context. Use
+    /// [`try_verify`](Self::try_verify) to capture them.
+    pub fn verify(&self) -> bool {
+        self.as_operation().verify()
+    }
+
+    /// Verifies a module reporting diagnostics on failure.
+    pub fn try_verify(&self) -> Result<(), Error> {
+        self.as_operation().try_verify()
+    }
+
     /// Converts an operation into a module.
     pub fn from_operation(operation: Operation) -> Option<Self> {
         unsafe { Self::from_option_raw(mlirModuleFromOperation(operation.into_raw())) }
@@ -88,6 +118,16 @@ impl<'c> Module<'c> {
     }
 }
 
+impl<'c> Clone for Module<'c> {
+    fn clone(&self) -> Self {
+        unsafe {
+            Self::from_raw(mlirModuleFromOperation(mlirOperationClone(
+                mlirModuleGetOperation(self.raw),
+            )))
+        }
+    }
+}
+
 impl<'c> Drop for Module<'c> {
     fn drop(&mut self) {
         unsafe { mlirModuleDestroy(self.raw) };
@@ -98,7 +138,13 @@ impl<'c> Drop for Module<'c> {
 mod tests {
     use super::*;
     use crate::{
-        ir::{attribute::StringAttribute, operation::OperationBuilder, Block, Region},
+        dialect::func,
+        ir::{
+            attribute::{StringAttribute, TypeAttribute},
+            operation::OperationBuilder,
+            r#type::FunctionType,
+            Block, Region,
+        },
         test::create_test_context,
     };
 
@@ -122,6 +168,55 @@ mod tests {
         assert!(Module::parse(&Context::new(), "module{").is_none());
     }
 
+    #[test]
+    fn parse_with_unregistered_dialect() {
+        let context = Context::new();
+        let source = "\"foo.bar\"() : () -> ()";
+
+        assert!(Module::parse(&context, source).is_none());
+
+        context.set_allow_unregistered_dialects(true);
+
+        assert!(Module::parse(&context, source).is_some());
+    }
+
+    #[test]
+    fn parse_file() {
+        let context = Context::new();
+        let path = std::env::temp_dir().join("melior_module_parse_file.mlir");
+        fs::write(&path, "module {}").unwrap();
+
+        assert_eq!(
+            Module::parse_file(&context, &path)
+                .unwrap()
+                .as_operation()
+                .to_string(),
+            "module {\n}\n"
+        );
+    }
+
+    #[test]
+    fn parse_file_with_syntax_error() {
+        let context = Context::new();
+        let path = std::env::temp_dir().join("melior_module_parse_file_with_syntax_error.mlir");
+        fs::write(&path, "module {").unwrap();
+
+        assert!(matches!(
+            Module::parse_file(&context, &path),
+            Err(Error::ParseModule(_))
+        ));
+    }
+
+    #[test]
+    fn parse_file_not_found() {
+        let context = Context::new();
+
+        assert!(matches!(
+            Module::parse_file(&context, Path::new("/nonexistent/melior/module.mlir")),
+            Err(Error::ReadFile { .. })
+        ));
+    }
+
     #[test]
     fn from_operation() {
         let context = create_test_context();
@@ -165,4 +260,86 @@ mod tests {
 
         assert!(module.as_operation().verify());
     }
+
+    #[test]
+    fn body() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+
+        for name in ["foo", "bar"] {
+            module.body().append_operation(func::func(
+                &context,
+                StringAttribute::new(&context, name),
+                TypeAttribute::new(FunctionType::new(&context, &[], &[]).into()),
+                {
+                    let block = Block::new(&[]);
+                    block.append_operation(func::r#return(&[], location));
+
+                    let region = Region::new();
+                    region.append_block(block);
+                    region
+                },
+                &[],
+                location,
+            ));
+        }
+
+        assert!(module.as_operation().verify());
+        assert_eq!(
+            module.as_operation().to_string(),
+            Module::parse(
+                &context,
+                "func.func @foo() { return }\nfunc.func @bar() { return }"
+            )
+            .unwrap()
+            .as_operation()
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+
+        assert!(module.verify());
+        assert_eq!(module.try_verify(), Ok(()));
+    }
+
+    #[test]
+    fn verify_failure() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+
+        module.body().append_operation(
+            OperationBuilder::new("arith.addi", Location::unknown(&context))
+                .build()
+                .unwrap(),
+        );
+
+        assert!(!module.verify());
+        assert!(matches!(module.try_verify(), Err(Error::Verify(_))));
+    }
+
+    #[test]
+    fn clone() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let module = Module::new(Location::unknown(&context));
+        let cloned = module.clone();
+
+        cloned.body().append_operation(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(module.as_operation().to_string(), "module {\n}\n");
+        assert_eq!(
+            cloned.as_operation().to_string(),
+            "module {\n  \"foo\"() : () -> ()\n}\n"
+        );
+    }
 }
@@ -1,16 +1,26 @@
 //! Operations and operation builders.
 
 mod builder;
+mod bytecode_writer_config;
+mod operand;
 mod printing_flags;
 mod result;
+mod walk_order;
+mod walk_result;
+mod wrapper;
 
 pub use self::{
-    builder::OperationBuilder, printing_flags::OperationPrintingFlags, result::OperationResult,
+    builder::OperationBuilder, bytecode_writer_config::BytecodeWriterConfig,
+    operand::OperationOperand, printing_flags::OperationPrintingFlags, result::OperationResult,
+    walk_order::WalkOrder, walk_result::WalkResult, wrapper::OperationWrapper,
+};
+use super::{
+    Attribute, AttributeLike, BlockRef, Identifier, IrMapping, RegionRef, Value, ValueLike,
 };
-use super::{Attribute, AttributeLike, BlockRef, Identifier, RegionRef, Value};
 use crate::{
     context::{Context, ContextRef},
-    utility::{print_callback, print_string_callback},
+    logical_result::LogicalResult,
+    utility::{collect_diagnostics, print_callback, print_string_callback, write_callback},
     Error, StringRef,
 };
 use core::{
@@ -18,18 +28,22 @@ use core::{
     mem::{forget, transmute},
 };
 use mlir_sys::{
-    mlirOperationClone, mlirOperationDestroy, mlirOperationDump, mlirOperationEqual,
-    mlirOperationGetAttribute, mlirOperationGetAttributeByName, mlirOperationGetBlock,
-    mlirOperationGetContext, mlirOperationGetName, mlirOperationGetNextInBlock,
-    mlirOperationGetNumAttributes, mlirOperationGetNumOperands, mlirOperationGetNumRegions,
-    mlirOperationGetNumResults, mlirOperationGetNumSuccessors, mlirOperationGetOperand,
-    mlirOperationGetRegion, mlirOperationGetResult, mlirOperationGetSuccessor, mlirOperationPrint,
-    mlirOperationPrintWithFlags, mlirOperationRemoveAttributeByName,
-    mlirOperationSetAttributeByName, mlirOperationVerify, MlirOperation,
+    mlirBlockGetParentRegion, mlirOperationClone, mlirOperationDestroy, mlirOperationDump,
+    mlirOperationEqual, mlirOperationGetAttribute, mlirOperationGetAttributeByName,
+    mlirOperationGetBlock, mlirOperationGetContext, mlirOperationGetName,
+    mlirOperationGetNextInBlock, mlirOperationGetNumAttributes, mlirOperationGetNumOperands,
+    mlirOperationGetNumRegions, mlirOperationGetNumResults, mlirOperationGetNumSuccessors,
+    mlirOperationGetOperand, mlirOperationGetParentOperation, mlirOperationGetRegion,
+    mlirOperationGetResult, mlirOperationGetSuccessor, mlirOperationPrint,
+    mlirOperationPrintWithFlags, mlirOperationRemoveAttributeByName, mlirOperationRemoveFromParent,
+    mlirOperationSetAttributeByName, mlirOperationSetOperand, mlirOperationSetOperands,
+    mlirOperationVerify, mlirOperationWalk, mlirOperationWriteBytecode,
+    mlirOperationWriteBytecodeWithConfig, MlirOperation,
 };
 use std::{
     ffi::c_void,
     fmt::{Debug, Display, Formatter},
+    io::{self, Write},
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
@@ -51,6 +65,18 @@ impl<'c> Operation<'c> {
         unsafe { Identifier::from_raw(mlirOperationGetName(self.raw)) }
     }
 
+    /// Converts an operation into a typed wrapper if their names match.
+    ///
+    /// It returns the operation back on failure so that it can be converted
+    /// into another wrapper.
+    pub fn downcast<T: OperationWrapper<'c>>(self) -> Result<T, Self> {
+        if self.name().as_string_ref().as_str() == Ok(T::name()) {
+            Ok(T::from_operation_unchecked(self))
+        } else {
+            Err(self)
+        }
+    }
+
     /// Gets a block.
     // TODO Store lifetime of block in operations, or create another type like
     // `AppendedOperationRef`?
@@ -58,6 +84,26 @@ impl<'c> Operation<'c> {
         unsafe { BlockRef::from_option_raw(mlirOperationGetBlock(self.raw)) }
     }
 
+    /// Gets a parent region.
+    ///
+    /// It returns `None` if an operation does not belong to any block.
+    pub fn parent_region(&self) -> Option<RegionRef<'c, '_>> {
+        let block = unsafe { mlirOperationGetBlock(self.raw) };
+
+        if block.ptr.is_null() {
+            None
+        } else {
+            unsafe { RegionRef::from_option_raw(mlirBlockGetParentRegion(block)) }
+        }
+    }
+
+    /// Gets a parent operation.
+    ///
+    /// It returns `None` for a top-level operation, such as a module.
+    pub fn parent_operation(&self) -> Option<OperationRef<'c, '_>> {
+        unsafe { OperationRef::from_option_raw(mlirOperationGetParentOperation(self.raw)) }
+    }
+
     /// Gets the number of operands.
     pub fn operand_count(&self) -> usize {
         unsafe { mlirOperationGetNumOperands(self.raw) as usize }
@@ -77,10 +123,38 @@ impl<'c> Operation<'c> {
     }
 
     /// Gets all operands.
-    pub fn operands(&self) -> impl Iterator<Item = Value<'c, '_>> {
+    pub fn operands(&self) -> impl ExactSizeIterator<Item = Value<'c, '_>> {
         (0..self.operand_count()).map(|index| self.operand(index).expect("valid operand index"))
     }
 
+    /// Sets an operand at a position.
+    pub fn set_operand(&mut self, index: usize, value: Value<'c, '_>) -> Result<(), Error> {
+        if index < self.operand_count() {
+            unsafe { mlirOperationSetOperand(self.raw, index as isize, value.to_raw()) }
+
+            Ok(())
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "operation operand",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Replaces all operands.
+    ///
+    /// The number of operands can differ from the current one.
+    pub fn set_operands(&mut self, values: &[Value<'c, '_>]) {
+        unsafe {
+            mlirOperationSetOperands(
+                self.raw,
+                values.len() as isize,
+                values.as_ptr() as *const _ as *const _,
+            )
+        }
+    }
+
     /// Gets the number of results.
     pub fn result_count(&self) -> usize {
         unsafe { mlirOperationGetNumResults(self.raw) as usize }
@@ -102,7 +176,7 @@ impl<'c> Operation<'c> {
     }
 
     /// Gets all results.
-    pub fn results(&self) -> impl Iterator<Item = OperationResult<'c, '_>> {
+    pub fn results(&self) -> impl ExactSizeIterator<Item = OperationResult<'c, '_>> {
         (0..self.result_count()).map(|index| self.result(index).expect("valid result index"))
     }
 
@@ -125,8 +199,8 @@ impl<'c> Operation<'c> {
     }
 
     /// Gets all regions.
-    pub fn regions(&self) -> impl Iterator<Item = RegionRef<'c, '_>> {
-        (0..self.region_count()).map(|index| self.region(index).expect("valid result index"))
+    pub fn regions(&self) -> impl ExactSizeIterator<Item = RegionRef<'c, '_>> {
+        (0..self.region_count()).map(|index| self.region(index).expect("valid region index"))
     }
 
     /// Gets the number of successors.
@@ -148,7 +222,7 @@ impl<'c> Operation<'c> {
     }
 
     /// Gets all successors.
-    pub fn successors(&self) -> impl Iterator<Item = BlockRef<'c, '_>> {
+    pub fn successors(&self) -> impl ExactSizeIterator<Item = BlockRef<'c, '_>> {
         (0..self.successor_count())
             .map(|index| self.successor(index).expect("valid successor index"))
     }
@@ -217,16 +291,150 @@ impl<'c> Operation<'c> {
             .ok_or(Error::AttributeNotFound(name.into()))
     }
 
+    /// Clones an operation replacing values referenced by it and its nested
+    /// operations with the ones in a mapping.
+    ///
+    /// Values defined inside the operation are remapped to their clones
+    /// automatically. So the mapping is only needed for values defined outside
+    /// of it, such as block arguments of a loop body.
+    pub fn clone_with_mapping(&self, mapping: &IrMapping<'c, '_>) -> Self {
+        let operation = self.clone();
+
+        operation.walk(WalkOrder::PreOrder, |operation| {
+            for (index, operand) in operation.operands().enumerate() {
+                if let Some(value) = mapping.lookup(operand) {
+                    unsafe {
+                        mlirOperationSetOperand(operation.to_raw(), index as isize, value.to_raw())
+                    }
+                }
+            }
+
+            WalkResult::Advance
+        });
+
+        operation
+    }
+
+    /// Removes an operation from its parent block.
+    ///
+    /// The returned operation is dropped with its nested operations unless it
+    /// is inserted into another block by
+    /// [`Block::append_operation`](crate::ir::Block::append_operation) or
+    /// other insertion methods, which take its ownership back. It returns
+    /// `None` if an operation does not belong to any block.
+    ///
+    /// # Safety
+    ///
+    /// This function might invalidate existing references to the operation if
+    /// you drop it too early.
+    pub unsafe fn remove_from_parent(&self) -> Option<Operation<'c>> {
+        if self.block().is_some() {
+            mlirOperationRemoveFromParent(self.raw);
+
+            Some(Operation::from_raw(self.raw))
+        } else {
+            None
+        }
+    }
+
     /// Gets the next operation in the same block.
     pub fn next_in_block(&self) -> Option<OperationRef<'c, '_>> {
         unsafe { OperationRef::from_option_raw(mlirOperationGetNextInBlock(self.raw)) }
     }
 
+    /// Walks an operation and its nested operations recursively.
+    ///
+    /// It returns `WalkResult::Interrupt` if a callback interrupts the walk, or
+    /// `WalkResult::Advance` otherwise.
+    pub fn walk<F: FnMut(OperationRef<'c, '_>) -> WalkResult>(
+        &self,
+        order: WalkOrder,
+        callback: F,
+    ) -> WalkResult {
+        struct State<F> {
+            callback: F,
+            interrupted: bool,
+            skipped: Option<MlirOperation>,
+        }
+
+        unsafe fn is_nested(mut operation: MlirOperation, ancestor: MlirOperation) -> bool {
+            loop {
+                operation = mlirOperationGetParentOperation(operation);
+
+                if operation.ptr.is_null() {
+                    return false;
+                } else if mlirOperationEqual(operation, ancestor) {
+                    return true;
+                }
+            }
+        }
+
+        unsafe extern "C" fn visit<'c, F: FnMut(OperationRef<'c, '_>) -> WalkResult>(
+            operation: MlirOperation,
+            data: *mut c_void,
+        ) {
+            let state = &mut *(data as *mut State<F>);
+
+            // The C API always walks all operations, so we emulate interruption and
+            // skipping by ignoring the rest of them.
+            if state.interrupted {
+                return;
+            } else if let Some(skipped) = state.skipped {
+                if is_nested(operation, skipped) {
+                    return;
+                }
+
+                state.skipped = None;
+            }
+
+            match (state.callback)(OperationRef::from_raw(operation)) {
+                WalkResult::Advance => {}
+                WalkResult::Interrupt => state.interrupted = true,
+                WalkResult::Skip => state.skipped = Some(operation),
+            }
+        }
+
+        let mut state = State {
+            callback,
+            interrupted: false,
+            skipped: None,
+        };
+
+        unsafe {
+            mlirOperationWalk(
+                self.raw,
+                Some(visit::<F>),
+                &mut state as *mut _ as *mut c_void,
+                order.to_raw(),
+            );
+        }
+
+        if state.interrupted {
+            WalkResult::Interrupt
+        } else {
+            WalkResult::Advance
+        }
+    }
+
     /// Verifies an operation.
+    ///
+    /// Verification errors are emitted as diagnostics to a context. Attach a
+    /// diagnostic handler to the context to capture them.
     pub fn verify(&self) -> bool {
         unsafe { mlirOperationVerify(self.raw) }
     }
 
+    /// Verifies an operation reporting diagnostics on failure.
+    pub fn try_verify(&self) -> Result<(), Error> {
+        let (verified, diagnostics) = collect_diagnostics(&self.context(), || self.verify());
+
+        if verified {
+            Ok(())
+        } else {
+            Err(Error::Verify(diagnostics.join("\n")))
+        }
+    }
+
     /// Dumps an operation.
     pub fn dump(&self) {
         unsafe { mlirOperationDump(self.raw) }
@@ -250,6 +458,68 @@ impl<'c> Operation<'c> {
         Ok(data.0)
     }
 
+    /// Prints an operation to a writer.
+    ///
+    /// Unlike `to_string`, it does not allocate an intermediate string.
+    pub fn print<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        let mut data: (_, io::Result<()>) = (writer, Ok(()));
+
+        unsafe {
+            mlirOperationPrint(
+                self.raw,
+                Some(write_callback::<W>),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1.map_err(|error| Error::Print(error.to_string()))
+    }
+
+    /// Writes an operation in bytecode.
+    pub fn write_bytecode<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        let mut data = (writer, Ok::<_, io::Error>(()));
+
+        unsafe {
+            mlirOperationWriteBytecode(
+                self.raw,
+                Some(write_callback::<W>),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1
+            .map_err(|error| Error::WriteBytecode(error.to_string()))
+    }
+
+    /// Writes an operation in bytecode with a configuration.
+    pub fn write_bytecode_with_config<W: Write>(
+        &self,
+        writer: &mut W,
+        config: &BytecodeWriterConfig,
+    ) -> Result<(), Error> {
+        let mut data = (writer, Ok::<_, io::Error>(()));
+
+        let result = LogicalResult::from_raw(unsafe {
+            mlirOperationWriteBytecodeWithConfig(
+                self.raw,
+                config.to_raw(),
+                Some(write_callback::<W>),
+                &mut data as *mut _ as *mut c_void,
+            )
+        });
+
+        data.1
+            .map_err(|error| Error::WriteBytecode(error.to_string()))?;
+
+        if result.is_success() {
+            Ok(())
+        } else {
+            Err(Error::WriteBytecode(
+                "unsupported bytecode writer configuration".into(),
+            ))
+        }
+    }
+
     /// Creates an operation from a raw object.
     ///
     /// # Safety
@@ -359,6 +629,17 @@ impl<'c, 'a> OperationRef<'c, 'a> {
         transmute(self)
     }
 
+    /// Erases an operation from its parent block and destroys it with its
+    /// nested operations.
+    ///
+    /// # Safety
+    ///
+    /// The operation must not be used anymore by any references, such as
+    /// other operation references, its results, and uses of the results.
+    pub unsafe fn erase(self) {
+        mlirOperationDestroy(self.raw)
+    }
+
     /// Converts an operation reference into a raw object.
     pub const fn to_raw(self) -> MlirOperation {
         self.raw
@@ -496,10 +777,51 @@ mod tests {
     use super::*;
     use crate::{
         context::Context,
-        ir::{attribute::StringAttribute, Block, Location, Region, Type},
+        ir::{attribute::StringAttribute, Block, Location, Module, Region, Type},
         test::create_test_context,
     };
+    use indoc::indoc;
     use pretty_assertions::assert_eq;
+    use std::io;
+
+    fn parse_walk_module(context: &Context) -> Module {
+        Module::parse(
+            context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                    %0 = arith.addi %arg0, %arg0 : i32
+                    %1 = arith.addi %0, %0 : i32
+                    return %1 : i32
+                }
+
+                func.func @bar(%arg0 : i32) -> i32 {
+                    %0 = arith.addi %arg0, %arg0 : i32
+                    return %0 : i32
+                }
+                "
+            ),
+        )
+        .unwrap()
+    }
+
+    fn walk_names<'c>(operation: &Operation<'c>, order: WalkOrder) -> Vec<Identifier<'c>> {
+        let mut names = vec![];
+
+        operation.walk(order, |operation| {
+            names.push(operation.name());
+            WalkResult::Advance
+        });
+
+        names
+    }
+
+    fn identifiers<'c>(context: &'c Context, names: &[&str]) -> Vec<Identifier<'c>> {
+        names
+            .iter()
+            .map(|name| Identifier::new(context, name))
+            .collect()
+    }
 
     #[test]
     fn new() {
@@ -538,6 +860,26 @@ mod tests {
         assert_eq!(operation.block().as_deref(), Some(&block));
     }
 
+    #[test]
+    fn parent_region_and_operation() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let module = Module::new(Location::unknown(&context));
+        let operation = module.body().append_operation(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            operation.parent_region(),
+            Some(module.as_operation().region(0).unwrap())
+        );
+        assert_eq!(operation.parent_operation(), Some(module.as_operation()));
+        assert_eq!(module.as_operation().parent_operation(), None);
+        assert_eq!(module.as_operation().parent_region(), None);
+    }
+
     #[test]
     fn block_none() {
         let context = create_test_context();
@@ -602,12 +944,175 @@ mod tests {
             .build()
             .unwrap();
 
+        assert_eq!(operation.operands().len(), 3);
         assert_eq!(
             operation.operands().skip(1).collect::<Vec<_>>(),
             vec![argument, argument]
         );
     }
 
+    #[test]
+    fn set_operand() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first: Value = block.argument(0).unwrap().into();
+        let second: Value = block.argument(1).unwrap().into();
+
+        let mut operation = OperationBuilder::new("foo", location)
+            .add_operands(&[first, first])
+            .build()
+            .unwrap();
+
+        assert_eq!(operation.set_operand(1, second), Ok(()));
+        assert_eq!(
+            operation.operands().collect::<Vec<_>>(),
+            vec![first, second]
+        );
+        assert_eq!(
+            operation.set_operand(2, second),
+            Err(Error::PositionOutOfBounds {
+                name: "operation operand",
+                value: operation.to_string(),
+                index: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn clone_with_mapping() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first: Value = block.argument(0).unwrap().into();
+        let second: Value = block.argument(1).unwrap().into();
+
+        let operation = OperationBuilder::new("foo", location)
+            .add_operands(&[first, second])
+            .add_regions([{
+                let block = Block::new(&[]);
+                block.append_operation(
+                    OperationBuilder::new("bar", location)
+                        .add_operands(&[first])
+                        .build()
+                        .unwrap(),
+                );
+
+                let region = Region::new();
+                region.append_block(block);
+                region
+            }])
+            .build()
+            .unwrap();
+
+        let mut mapping = IrMapping::new();
+        mapping.map(first, second);
+
+        let cloned = operation.clone_with_mapping(&mapping);
+
+        assert_eq!(cloned.operands().collect::<Vec<_>>(), vec![second, second]);
+        assert_eq!(
+            cloned
+                .region(0)
+                .unwrap()
+                .first_block()
+                .unwrap()
+                .first_operation()
+                .unwrap()
+                .operand(0)
+                .unwrap(),
+            second
+        );
+        assert_eq!(
+            operation.operands().collect::<Vec<_>>(),
+            vec![first, second]
+        );
+    }
+
+    #[test]
+    fn set_operands() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first: Value = block.argument(0).unwrap().into();
+        let second: Value = block.argument(1).unwrap().into();
+
+        let mut operation = OperationBuilder::new("foo", location)
+            .add_operands(&[first])
+            .build()
+            .unwrap();
+
+        operation.set_operands(&[second, first, second]);
+
+        assert_eq!(
+            operation.operands().collect::<Vec<_>>(),
+            vec![second, first, second]
+        );
+    }
+
+    #[test]
+    fn results() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let r#type = Type::index(&context);
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_results(&[r#type, r#type])
+            .build()
+            .unwrap();
+
+        assert_eq!(operation.results().len(), 2);
+        assert_eq!(
+            operation
+                .results()
+                .map(|result| result.result_number())
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn successors() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        let first = region.append_block(Block::new(&[]));
+        let second = region.append_block(Block::new(&[]));
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .add_successors(&[&first, &second])
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(operation.successor_count(), 2);
+        assert_eq!(operation.successor(0), Ok(first));
+        assert_eq!(
+            operation.successors().collect::<Vec<_>>(),
+            vec![first, second]
+        );
+        assert_eq!(
+            operation.successor(2),
+            Err(Error::PositionOutOfBounds {
+                name: "successor",
+                value: operation.to_string(),
+                index: 2,
+            })
+        );
+    }
+
     #[test]
     fn regions() {
         let context = create_test_context();
@@ -624,6 +1129,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn region_count() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_regions([Region::new(), Region::new()])
+            .build()
+            .unwrap();
+
+        assert_eq!(operation.region_count(), 2);
+        assert_eq!(operation.regions().len(), 2);
+        assert!(operation.region(1).is_ok());
+        assert!(operation.region(2).is_err());
+    }
+
     #[test]
     fn attribute() {
         let context = create_test_context();
@@ -657,6 +1178,270 @@ mod tests {
         )
     }
 
+    #[test]
+    fn walk_pre_order() {
+        let context = create_test_context();
+        let module = parse_walk_module(&context);
+
+        assert_eq!(
+            walk_names(&module.as_operation(), WalkOrder::PreOrder),
+            identifiers(
+                &context,
+                &[
+                    "builtin.module",
+                    "func.func",
+                    "arith.addi",
+                    "arith.addi",
+                    "func.return",
+                    "func.func",
+                    "arith.addi",
+                    "func.return",
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn walk_post_order() {
+        let context = create_test_context();
+        let module = parse_walk_module(&context);
+
+        assert_eq!(
+            walk_names(&module.as_operation(), WalkOrder::PostOrder),
+            identifiers(
+                &context,
+                &[
+                    "arith.addi",
+                    "arith.addi",
+                    "func.return",
+                    "func.func",
+                    "arith.addi",
+                    "func.return",
+                    "func.func",
+                    "builtin.module",
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn walk_interrupt() {
+        let context = create_test_context();
+        let module = parse_walk_module(&context);
+        let mut count = 0;
+
+        let result = module
+            .as_operation()
+            .walk(WalkOrder::PreOrder, |operation| {
+                if operation.name() == Identifier::new(&context, "arith.addi") {
+                    return WalkResult::Interrupt;
+                }
+
+                count += 1;
+                WalkResult::Advance
+            });
+
+        assert_eq!(result, WalkResult::Interrupt);
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn walk_skip() {
+        let context = create_test_context();
+        let module = parse_walk_module(&context);
+        let mut names = vec![];
+
+        let result = module
+            .as_operation()
+            .walk(WalkOrder::PreOrder, |operation| {
+                names.push(operation.name());
+
+                if operation.name() == Identifier::new(&context, "func.func") {
+                    WalkResult::Skip
+                } else {
+                    WalkResult::Advance
+                }
+            });
+
+        assert_eq!(result, WalkResult::Advance);
+        assert_eq!(
+            names,
+            identifiers(&context, &["builtin.module", "func.func", "func.func"])
+        );
+    }
+
+    #[test]
+    fn remove_from_parent() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+        block.append_operation(OperationBuilder::new("foo", location).build().unwrap());
+        let operation =
+            block.append_operation(OperationBuilder::new("bar", location).build().unwrap());
+
+        let operation = unsafe { operation.remove_from_parent() }.unwrap();
+
+        assert_eq!(block.iter().count(), 1);
+        assert_eq!(operation.block(), None);
+        assert!(unsafe { operation.remove_from_parent() }.is_none());
+
+        let other_block = Block::new(&[]);
+        let operation = other_block.append_operation(operation);
+
+        assert_eq!(operation.name(), Identifier::new(&context, "bar"));
+        assert_eq!(other_block.iter().count(), 1);
+    }
+
+    #[test]
+    fn erase() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+        let operation =
+            block.append_operation(OperationBuilder::new("foo", location).build().unwrap());
+        block.append_operation(OperationBuilder::new("bar", location).build().unwrap());
+
+        unsafe { operation.erase() };
+
+        assert_eq!(
+            block.first_operation().unwrap().name(),
+            Identifier::new(&context, "bar")
+        );
+        assert_eq!(block.iter().count(), 1);
+    }
+
+    #[test]
+    fn print() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+        let mut buffer = vec![];
+
+        module.as_operation().print(&mut buffer).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            module.as_operation().to_string()
+        );
+    }
+
+    #[test]
+    fn print_failure() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+        let mut buffer: &mut [u8] = &mut [];
+
+        assert!(matches!(
+            module.as_operation().print(&mut buffer),
+            Err(Error::Print(_))
+        ));
+    }
+
+    #[test]
+    fn write_bytecode() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+        let mut buffer = vec![];
+
+        module.as_operation().write_bytecode(&mut buffer).unwrap();
+
+        assert!(buffer.starts_with(b"ML\xefR"));
+    }
+
+    #[test]
+    fn write_bytecode_with_config() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+        let mut buffer = vec![];
+
+        module
+            .as_operation()
+            .write_bytecode_with_config(
+                &mut buffer,
+                &BytecodeWriterConfig::new().desired_version(1),
+            )
+            .unwrap();
+
+        assert!(buffer.starts_with(b"ML\xefR"));
+    }
+
+    #[test]
+    fn write_bytecode_with_writer_error() {
+        struct FailingWriter;
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::Other, "foo"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+
+        assert_eq!(
+            module.as_operation().write_bytecode(&mut FailingWriter),
+            Err(Error::WriteBytecode("foo".into()))
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        assert!(OperationBuilder::new("foo", Location::unknown(&context))
+            .build()
+            .unwrap()
+            .verify());
+    }
+
+    #[test]
+    fn verify_failure() {
+        let context = create_test_context();
+        let mut diagnostics = vec![];
+
+        context.attach_diagnostic_handler(|diagnostic| {
+            diagnostics.push(diagnostic.to_string());
+            true
+        });
+
+        assert!(
+            !OperationBuilder::new("arith.addi", Location::unknown(&context))
+                .build()
+                .unwrap()
+                .verify()
+        );
+        assert!(!diagnostics.is_empty());
+    }
+
+    #[test]
+    fn try_verify() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        assert_eq!(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .build()
+                .unwrap()
+                .try_verify(),
+            Ok(())
+        );
+        assert!(matches!(
+            OperationBuilder::new("arith.addi", Location::unknown(&context))
+                .build()
+                .unwrap()
+                .try_verify(),
+            Err(Error::Verify(_))
+        ));
+    }
+
     #[test]
     fn clone() {
         let context = create_test_context();
@@ -2,18 +2,24 @@ use super::Operation;
 use crate::{
     context::Context,
     ir::{Attribute, AttributeLike, Block, Identifier, Location, Region, Type, Value},
+    logical_result::LogicalResult,
     string_ref::StringRef,
     Error,
 };
 use mlir_sys::{
-    mlirNamedAttributeGet, mlirOperationCreate, mlirOperationStateAddAttributes,
+    mlirDictionaryAttrGet, mlirIdentifierGet, mlirInferTypeOpInterfaceInferReturnTypes,
+    mlirInferTypeOpInterfaceTypeID, mlirLocationGetContext, mlirNamedAttributeGet,
+    mlirOperationCreate, mlirOperationImplementsInterfaceStatic, mlirOperationStateAddAttributes,
     mlirOperationStateAddOperands, mlirOperationStateAddOwnedRegions, mlirOperationStateAddResults,
     mlirOperationStateAddSuccessors, mlirOperationStateEnableResultTypeInference,
-    mlirOperationStateGet, MlirOperationState,
+    mlirOperationStateGet, MlirOperationState, MlirType,
 };
 use std::{
+    ffi::c_void,
     marker::PhantomData,
     mem::{forget, transmute, ManuallyDrop},
+    ptr::null_mut,
+    slice,
 };
 
 /// An operation builder.
@@ -31,7 +37,19 @@ impl<'c> OperationBuilder<'c> {
         }
     }
 
+    /// Adds a result.
+    pub fn add_result(self, r#type: Type<'c>) -> Self {
+        self.add_results(&[r#type])
+    }
+
     /// Adds results.
+    ///
+    /// Result types are appended to the ones added so far in order. They are
+    /// not checked against the definition of an operation on build. So an
+    /// operation with a wrong number or types of results is built
+    /// successfully but fails on verification. Use
+    /// [`enable_result_type_inference`](Self::enable_result_type_inference)
+    /// instead for operations that infer their result types.
     pub fn add_results(mut self, results: &[Type<'c>]) -> Self {
         unsafe {
             mlirOperationStateAddResults(
@@ -57,7 +75,18 @@ impl<'c> OperationBuilder<'c> {
         self
     }
 
+    /// Adds a region.
+    ///
+    /// The builder takes ownership of the region with its blocks and
+    /// operations, which are moved into an operation on build.
+    pub fn add_region(self, region: Region<'c>) -> Self {
+        self.add_regions([region])
+    }
+
     /// Adds regions.
+    ///
+    /// Like [`add_region`](Self::add_region), the builder takes ownership of
+    /// the regions.
     pub fn add_regions<const N: usize>(mut self, regions: [Region<'c>; N]) -> Self {
         unsafe {
             mlirOperationStateAddOwnedRegions(
@@ -98,8 +127,28 @@ impl<'c> OperationBuilder<'c> {
         self
     }
 
+    /// Adds an attribute.
+    pub fn add_attribute(self, name: &str, attribute: Attribute<'c>) -> Self {
+        let identifier = unsafe {
+            Identifier::from_raw(mlirIdentifierGet(
+                mlirLocationGetContext(self.raw.location),
+                StringRef::new(name).to_raw(),
+            ))
+        };
+
+        self.add_named_attributes([(identifier, attribute)])
+    }
+
     /// Adds attributes.
-    pub fn add_attributes(mut self, attributes: &[(Identifier<'c>, Attribute<'c>)]) -> Self {
+    pub fn add_attributes(self, attributes: &[(Identifier<'c>, Attribute<'c>)]) -> Self {
+        self.add_named_attributes(attributes.iter().copied())
+    }
+
+    /// Adds named attributes.
+    pub fn add_named_attributes(
+        mut self,
+        attributes: impl IntoIterator<Item = (Identifier<'c>, Attribute<'c>)>,
+    ) -> Self {
         for (identifier, attribute) in attributes {
             unsafe {
                 mlirOperationStateAddAttributes(
@@ -117,12 +166,57 @@ impl<'c> OperationBuilder<'c> {
     }
 
     /// Enables result type inference.
+    ///
+    /// Result types are inferred on build if an operation implements
+    /// `InferTypeOpInterface`. Otherwise, the build fails.
     pub fn enable_result_type_inference(mut self) -> Self {
         unsafe { mlirOperationStateEnableResultTypeInference(&mut self.raw) }
 
         self
     }
 
+    /// Infers result types from operands, attributes, and regions added so far
+    /// without building an operation.
+    pub fn try_infer_result_types(&self) -> Result<Vec<Type<'c>>, Error> {
+        unsafe extern "C" fn callback(count: isize, types: *mut MlirType, data: *mut c_void) {
+            (*(data as *mut Vec<MlirType>)).extend(slice::from_raw_parts(types, count as usize));
+        }
+
+        let mut types = Vec::<MlirType>::new();
+
+        unsafe {
+            let context = mlirLocationGetContext(self.raw.location);
+
+            if !mlirOperationImplementsInterfaceStatic(
+                self.raw.name,
+                context,
+                mlirInferTypeOpInterfaceTypeID(),
+            ) {
+                return Err(Error::InferResultTypes);
+            }
+
+            let result = LogicalResult::from_raw(mlirInferTypeOpInterfaceInferReturnTypes(
+                self.raw.name,
+                context,
+                self.raw.location,
+                self.raw.nOperands,
+                self.raw.operands,
+                mlirDictionaryAttrGet(context, self.raw.nAttributes, self.raw.attributes),
+                null_mut(),
+                self.raw.nRegions,
+                self.raw.regions,
+                Some(callback),
+                &mut types as *mut _ as *mut c_void,
+            ));
+
+            if result.is_success() {
+                Ok(types.into_iter().map(|raw| Type::from_raw(raw)).collect())
+            } else {
+                Err(Error::InferResultTypes)
+            }
+        }
+    }
+
     /// Builds an operation.
     pub fn build(mut self) -> Result<Operation<'c>, Error> {
         unsafe { Operation::from_option_raw(mlirOperationCreate(&mut self.raw)) }
@@ -175,6 +269,38 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn add_result() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let r#type = Type::index(&context);
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_result(r#type)
+            .add_results(&[r#type, r#type])
+            .build()
+            .unwrap();
+
+        assert_eq!(operation.result_count(), 3);
+    }
+
+    #[test]
+    fn add_results_with_mismatched_count() {
+        let context = create_test_context();
+        let r#type = Type::index(&context);
+
+        let operation = OperationBuilder::new("arith.constant", Location::unknown(&context))
+            .add_attributes(&[(
+                Identifier::new(&context, "value"),
+                Attribute::parse(&context, "0 : index").unwrap(),
+            )])
+            .add_results(&[r#type, r#type])
+            .build()
+            .unwrap();
+
+        assert!(!operation.verify());
+    }
+
     #[test]
     fn add_regions() {
         let context = create_test_context();
@@ -186,6 +312,36 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn add_region() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        block.append_operation(OperationBuilder::new("bar", location).build().unwrap());
+
+        let operation = OperationBuilder::new("foo", location)
+            .add_region(region)
+            .add_regions_vec(vec![Region::new()])
+            .build()
+            .unwrap();
+
+        assert_eq!(operation.region_count(), 2);
+        assert_eq!(
+            operation
+                .region(0)
+                .unwrap()
+                .first_block()
+                .unwrap()
+                .first_operation()
+                .unwrap()
+                .name(),
+            Identifier::new(&context, "bar")
+        );
+    }
+
     #[test]
     fn add_successors() {
         let context = create_test_context();
@@ -211,6 +367,40 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn add_attribute() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        assert_eq!(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .add_attribute("foo", Attribute::parse(&context, "unit").unwrap())
+                .build()
+                .unwrap()
+                .attribute("foo"),
+            Ok(Attribute::parse(&context, "unit").unwrap())
+        );
+    }
+
+    #[test]
+    fn add_named_attributes() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_named_attributes(["foo", "bar"].into_iter().map(|name| {
+                (
+                    Identifier::new(&context, name),
+                    Attribute::parse(&context, "unit").unwrap(),
+                )
+            }))
+            .build()
+            .unwrap();
+
+        assert!(operation.has_attribute("foo"));
+        assert!(operation.has_attribute("bar"));
+    }
+
     #[test]
     fn enable_result_type_inference() {
         let context = create_test_context();
@@ -233,4 +423,32 @@ mod tests {
             r#type,
         );
     }
+
+    #[test]
+    fn try_infer_result_types() {
+        let context = create_test_context();
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument = block.argument(0).unwrap().into();
+
+        assert_eq!(
+            OperationBuilder::new("arith.addi", location)
+                .add_operands(&[argument, argument])
+                .try_infer_result_types(),
+            Ok(vec![r#type])
+        );
+    }
+
+    #[test]
+    fn try_infer_result_types_without_interface() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        assert_eq!(
+            OperationBuilder::new("foo", Location::unknown(&context)).try_infer_result_types(),
+            Err(Error::InferResultTypes)
+        );
+    }
 }
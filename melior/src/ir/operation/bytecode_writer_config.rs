@@ -0,0 +1,39 @@
+use mlir_sys::{
+    mlirBytecodeWriterConfigCreate, mlirBytecodeWriterConfigDesiredEmitVersion,
+    mlirBytecodeWriterConfigDestroy, MlirBytecodeWriterConfig,
+};
+
+/// A bytecode writer configuration.
+#[derive(Debug)]
+pub struct BytecodeWriterConfig(MlirBytecodeWriterConfig);
+
+impl BytecodeWriterConfig {
+    /// Creates a bytecode writer configuration.
+    pub fn new() -> Self {
+        Self(unsafe { mlirBytecodeWriterConfigCreate() })
+    }
+
+    /// Sets a desired bytecode version to emit.
+    pub fn desired_version(self, version: i64) -> Self {
+        unsafe { mlirBytecodeWriterConfigDesiredEmitVersion(self.0, version) }
+
+        self
+    }
+
+    /// Converts a bytecode writer configuration into a raw object.
+    pub const fn to_raw(&self) -> MlirBytecodeWriterConfig {
+        self.0
+    }
+}
+
+impl Drop for BytecodeWriterConfig {
+    fn drop(&mut self) {
+        unsafe { mlirBytecodeWriterConfigDestroy(self.0) }
+    }
+}
+
+impl Default for BytecodeWriterConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
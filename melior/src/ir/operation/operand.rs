@@ -0,0 +1,93 @@
+use crate::{ir::OperationRef, Context};
+use mlir_sys::{
+    mlirOpOperandGetNextUse, mlirOpOperandGetOperandNumber, mlirOpOperandGetOwner, MlirOpOperand,
+};
+use std::marker::PhantomData;
+
+/// An operation operand, which is a use of a value by an operation.
+#[derive(Clone, Copy, Debug)]
+pub struct OperationOperand<'c, 'a> {
+    raw: MlirOpOperand,
+    _context: PhantomData<&'c Context>,
+    _parent: PhantomData<&'a ()>,
+}
+
+impl<'c, 'a> OperationOperand<'c, 'a> {
+    /// Gets an owner operation.
+    pub fn owner(&self) -> OperationRef<'c, 'a> {
+        unsafe { OperationRef::from_raw(mlirOpOperandGetOwner(self.raw)) }
+    }
+
+    /// Gets an operand number in an owner operation.
+    pub fn operand_number(&self) -> usize {
+        unsafe { mlirOpOperandGetOperandNumber(self.raw) as usize }
+    }
+
+    /// Gets the next use of the same value.
+    pub fn next_use(&self) -> Option<Self> {
+        unsafe { Self::from_option_raw(mlirOpOperandGetNextUse(self.raw)) }
+    }
+
+    /// Creates an operation operand from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirOpOperand) -> Self {
+        Self {
+            raw,
+            _context: Default::default(),
+            _parent: Default::default(),
+        }
+    }
+
+    /// Creates an optional operation operand from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(raw: MlirOpOperand) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+
+    /// Converts an operation operand into a raw object.
+    pub const fn to_raw(self) -> MlirOpOperand {
+        self.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ir::{operation::OperationBuilder, Block, Location, Type, Value},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn owner_and_operand_number() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let argument = Value::from(block.argument(1).unwrap());
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[block.argument(0).unwrap().into(), argument])
+                .build()
+                .unwrap(),
+        );
+
+        let operand = argument.uses().next().unwrap();
+
+        assert_eq!(operand.owner(), operation);
+        assert_eq!(operand.operand_number(), 1);
+        assert!(operand.next_use().is_none());
+    }
+}
@@ -18,7 +18,7 @@ impl<'c, 'a> OperationResult<'c, 'a> {
     }
 
     /// Gets an owner operation.
-    pub fn owner(&self) -> OperationRef {
+    pub fn owner(&self) -> OperationRef<'c, 'a> {
         unsafe { OperationRef::from_raw(mlirOpResultGetOwner(self.value.to_raw())) }
     }
 
@@ -82,9 +82,19 @@ mod tests {
     #[test]
     fn owner() {
         let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
         let r#type = Type::parse(&context, "index").unwrap();
-        let block = Block::new(&[(r#type, Location::unknown(&context))]);
+        let block = Block::new(&[]);
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .add_results(&[r#type, r#type])
+                .build()
+                .unwrap(),
+        );
+        let result = operation.result(1).unwrap();
 
-        assert_eq!(&*block.argument(0).unwrap().owner(), &block);
+        assert_eq!(result.result_number(), 1);
+        assert_eq!(result.owner(), operation);
     }
 }
@@ -0,0 +1,20 @@
+use mlir_sys::{MlirWalkOrder, MlirWalkOrder_MlirWalkPostOrder, MlirWalkOrder_MlirWalkPreOrder};
+
+/// A walk order of operations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkOrder {
+    /// Visits a parent operation before its children.
+    PreOrder,
+    /// Visits a parent operation after its children.
+    PostOrder,
+}
+
+impl WalkOrder {
+    /// Converts a walk order into a raw object.
+    pub const fn to_raw(self) -> MlirWalkOrder {
+        match self {
+            Self::PreOrder => MlirWalkOrder_MlirWalkPreOrder,
+            Self::PostOrder => MlirWalkOrder_MlirWalkPostOrder,
+        }
+    }
+}
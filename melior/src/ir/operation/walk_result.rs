@@ -0,0 +1,13 @@
+/// A result of visiting an operation during a walk.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkResult {
+    /// Continues walking.
+    Advance,
+    /// Stops walking.
+    Interrupt,
+    /// Skips operations nested in a current operation.
+    ///
+    /// This is equivalent to `Advance` in post-order walks because nested
+    /// operations are already visited.
+    Skip,
+}
@@ -0,0 +1,12 @@
+use super::Operation;
+
+/// Trait for typed wrappers of operations, such as ones generated from ODS.
+///
+/// See [`Operation::downcast`].
+pub trait OperationWrapper<'c>: Into<Operation<'c>> {
+    /// Gets an operation name.
+    fn name() -> &'static str;
+
+    /// Creates a wrapper from an operation without checking its name.
+    fn from_operation_unchecked(operation: Operation<'c>) -> Self;
+}
@@ -1,10 +1,11 @@
 use super::{Block, BlockRef};
 use mlir_sys::{
-    mlirRegionAppendOwnedBlock, mlirRegionCreate, mlirRegionDestroy, mlirRegionEqual,
-    mlirRegionGetFirstBlock, mlirRegionInsertOwnedBlockAfter, mlirRegionInsertOwnedBlockBefore,
-    MlirRegion,
+    mlirBlockGetNextInRegion, mlirRegionAppendOwnedBlock, mlirRegionCreate, mlirRegionDestroy,
+    mlirRegionEqual, mlirRegionGetFirstBlock, mlirRegionInsertOwnedBlockAfter,
+    mlirRegionInsertOwnedBlockBefore, MlirRegion,
 };
 use std::{
+    iter::successors,
     marker::PhantomData,
     mem::{forget, transmute},
     ops::Deref,
@@ -39,6 +40,13 @@ impl<'c> Region<'c> {
         }
     }
 
+    /// Returns an iterator over blocks in a region.
+    pub fn iter(&self) -> impl Iterator<Item = BlockRef<'c, '_>> {
+        successors(self.first_block(), |block| unsafe {
+            BlockRef::from_option_raw(mlirBlockGetNextInRegion(block.to_raw()))
+        })
+    }
+
     /// Inserts a block after another block.
     pub fn insert_block_after(&self, one: BlockRef<'c, '_>, other: Block<'c>) -> BlockRef<'c, '_> {
         unsafe {
@@ -176,14 +184,30 @@ mod tests {
         assert!(region.first_block().is_some());
     }
 
+    #[test]
+    fn iter() {
+        let region = Region::new();
+
+        assert_eq!(region.iter().count(), 0);
+
+        let first_block = region.append_block(Block::new(&[]));
+        let second_block = region.append_block(Block::new(&[]));
+
+        assert_eq!(
+            region.iter().collect::<Vec<_>>(),
+            vec![first_block, second_block]
+        );
+    }
+
     #[test]
     fn insert_block_after() {
         let region = Region::new();
 
         let block = region.append_block(Block::new(&[]));
-        region.insert_block_after(block, Block::new(&[]));
+        let other_block = region.insert_block_after(block, Block::new(&[]));
 
         assert_eq!(region.first_block(), Some(block));
+        assert_eq!(block.next_in_region(), Some(other_block));
     }
 
     #[test]
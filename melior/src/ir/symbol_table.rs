@@ -0,0 +1,256 @@
+//! Symbol tables.
+
+use super::{Attribute, Operation, OperationRef};
+use crate::{logical_result::LogicalResult, string_ref::StringRef, Error};
+use mlir_sys::{
+    mlirSymbolTableCreate, mlirSymbolTableDestroy, mlirSymbolTableErase, mlirSymbolTableInsert,
+    mlirSymbolTableLookup, mlirSymbolTableReplaceAllSymbolUses, MlirSymbolTable,
+};
+use std::marker::PhantomData;
+
+/// A symbol table.
+#[derive(Debug)]
+pub struct SymbolTable<'c, 'a> {
+    raw: MlirSymbolTable,
+    _operation: PhantomData<&'a Operation<'c>>,
+}
+
+impl<'c, 'a> SymbolTable<'c, 'a> {
+    /// Creates a symbol table for an operation.
+    ///
+    /// It returns `None` if the operation is not a symbol table.
+    pub fn new(operation: OperationRef<'c, 'a>) -> Option<Self> {
+        unsafe { Self::from_option_raw(mlirSymbolTableCreate(operation.to_raw())) }
+    }
+
+    /// Looks up a symbol with a name.
+    pub fn lookup(&self, name: &str) -> Option<OperationRef<'c, 'a>> {
+        unsafe {
+            OperationRef::from_option_raw(mlirSymbolTableLookup(
+                self.raw,
+                StringRef::new(name).to_raw(),
+            ))
+        }
+    }
+
+    /// Inserts a symbol operation into a symbol table.
+    ///
+    /// The operation is renamed if its name collides with an existing symbol,
+    /// and its possibly renamed symbol name is returned. It is also inserted
+    /// into the body of the symbol table operation unless it is there already.
+    pub fn insert(&mut self, operation: OperationRef<'c, '_>) -> Attribute<'c> {
+        unsafe { Attribute::from_raw(mlirSymbolTableInsert(self.raw, operation.to_raw())) }
+    }
+
+    /// Removes a symbol operation from a symbol table and destroys it.
+    ///
+    /// # Safety
+    ///
+    /// The operation must not be used anymore by any references, such as
+    /// other operation references, its results, and uses of the results.
+    pub unsafe fn erase(&mut self, operation: OperationRef<'c, '_>) {
+        mlirSymbolTableErase(self.raw, operation.to_raw())
+    }
+
+    /// Creates a symbol table from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirSymbolTable) -> Self {
+        Self {
+            raw,
+            _operation: Default::default(),
+        }
+    }
+
+    /// Creates an optional symbol table from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(raw: MlirSymbolTable) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+
+    /// Converts a symbol table into a raw object.
+    pub const fn to_raw(&self) -> MlirSymbolTable {
+        self.raw
+    }
+}
+
+impl<'c, 'a> Drop for SymbolTable<'c, 'a> {
+    fn drop(&mut self) {
+        unsafe { mlirSymbolTableDestroy(self.raw) }
+    }
+}
+
+/// Replaces all uses of a symbol with a new one in nested operations of a
+/// given operation.
+pub fn replace_all_symbol_uses(
+    old_symbol: &str,
+    new_symbol: &str,
+    from: OperationRef,
+) -> Result<(), Error> {
+    let result = LogicalResult::from_raw(unsafe {
+        mlirSymbolTableReplaceAllSymbolUses(
+            StringRef::new(old_symbol).to_raw(),
+            StringRef::new(new_symbol).to_raw(),
+            from.to_raw(),
+        )
+    });
+
+    if result.is_success() {
+        Ok(())
+    } else {
+        Err(Error::ReplaceAllSymbolUses(old_symbol.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialect::func,
+        ir::{
+            attribute::{StringAttribute, TypeAttribute},
+            r#type::FunctionType,
+            Identifier, Location, Module, Region,
+        },
+        test::create_test_context,
+        Context,
+    };
+    use indoc::indoc;
+
+    fn parse_module(context: &Context) -> Module {
+        Module::parse(
+            context,
+            indoc!(
+                "
+                func.func @foo() {
+                    return
+                }
+
+                func.func @bar() {
+                    func.call @foo() : () -> ()
+                    return
+                }
+                "
+            ),
+        )
+        .unwrap()
+    }
+
+    fn create_function<'c>(context: &'c Context, name: &str) -> Operation<'c> {
+        func::func(
+            context,
+            StringAttribute::new(context, name),
+            TypeAttribute::new(FunctionType::new(context, &[], &[]).into()),
+            Region::new(),
+            &[(
+                Identifier::new(context, "sym_visibility"),
+                StringAttribute::new(context, "private").into(),
+            )],
+            Location::unknown(context),
+        )
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+
+        assert!(SymbolTable::new(module.as_operation()).is_some());
+    }
+
+    #[test]
+    fn new_none() {
+        let context = create_test_context();
+        let module = Module::new(Location::unknown(&context));
+        let body = module.body();
+        let function = body.append_operation(create_function(&context, "foo"));
+
+        assert!(SymbolTable::new(function).is_none());
+    }
+
+    #[test]
+    fn lookup() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+
+        assert_eq!(table.lookup("foo"), module.body().first_operation());
+    }
+
+    #[test]
+    fn lookup_none() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let table = SymbolTable::new(module.as_operation()).unwrap();
+
+        assert_eq!(table.lookup("baz"), None);
+    }
+
+    #[test]
+    fn insert() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+        let body = module.body();
+
+        let function = body.append_operation(create_function(&context, "baz"));
+
+        assert_eq!(
+            table.insert(function),
+            StringAttribute::new(&context, "baz").into()
+        );
+        assert_eq!(table.lookup("baz"), Some(function));
+    }
+
+    #[test]
+    fn insert_with_rename() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+        let body = module.body();
+
+        let function = body.append_operation(create_function(&context, "foo"));
+
+        assert_ne!(
+            table.insert(function),
+            StringAttribute::new(&context, "foo").into()
+        );
+        assert_ne!(table.lookup("foo"), Some(function));
+    }
+
+    #[test]
+    fn erase() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+        let mut table = SymbolTable::new(module.as_operation()).unwrap();
+
+        let function = table.lookup("bar").unwrap();
+        unsafe { table.erase(function) };
+
+        assert_eq!(table.lookup("bar"), None);
+    }
+
+    #[test]
+    fn replace_all_symbol_uses() {
+        let context = create_test_context();
+        let module = parse_module(&context);
+
+        assert_eq!(
+            super::replace_all_symbol_uses("foo", "baz", module.as_operation()),
+            Ok(())
+        );
+        assert!(module
+            .as_operation()
+            .to_string()
+            .contains("call @baz() : () -> ()"));
+    }
+}
@@ -2,22 +2,44 @@
 
 #[macro_use]
 mod r#macro;
+mod complex;
+mod float;
 mod function;
 pub mod id;
 mod integer;
 mod mem_ref;
+mod opaque;
 mod ranked_tensor;
+mod shaped;
 mod shaped_type_like;
 mod tuple;
 mod type_like;
+mod unranked_tensor;
+mod vector;
 
 pub use self::{
-    function::FunctionType, id::TypeId, integer::IntegerType, mem_ref::MemRefType,
-    ranked_tensor::RankedTensorType, shaped_type_like::ShapedTypeLike, tuple::TupleType,
+    complex::ComplexType,
+    float::FloatType,
+    function::FunctionType,
+    id::TypeId,
+    integer::IntegerType,
+    mem_ref::MemRefType,
+    opaque::OpaqueType,
+    ranked_tensor::RankedTensorType,
+    shaped::ShapedType,
+    shaped_type_like::{is_dynamic_size, ShapedTypeLike, DYNAMIC},
+    tuple::TupleType,
     type_like::TypeLike,
+    unranked_tensor::UnrankedTensorType,
+    vector::VectorType,
 };
 use super::Location;
-use crate::{context::Context, string_ref::StringRef, utility::print_callback};
+use crate::{
+    context::Context,
+    string_ref::StringRef,
+    utility::{collect_diagnostics, print_callback, write_callback},
+    Error,
+};
 use mlir_sys::{
     mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet, mlirIndexTypeGet,
     mlirNoneTypeGet, mlirTypeEqual, mlirTypeParseGet, mlirTypePrint, mlirVectorTypeGet,
@@ -26,6 +48,8 @@ use mlir_sys::{
 use std::{
     ffi::c_void,
     fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    io::{self, Write},
     marker::PhantomData,
 };
 
@@ -48,6 +72,13 @@ impl<'c> Type<'c> {
         }
     }
 
+    /// Parses a type reporting diagnostics on failure.
+    pub fn try_parse(context: &'c Context, source: &str) -> Result<Self, Error> {
+        let (r#type, diagnostics) = collect_diagnostics(context, || Self::parse(context, source));
+
+        r#type.ok_or_else(|| Error::ParseType(diagnostics.join("\n")))
+    }
+
     /// Creates a bfloat16 type.
     pub fn bfloat16(context: &'c Context) -> Self {
         unsafe { Self::from_raw(mlirBF16TypeGet(context.to_raw())) }
@@ -78,6 +109,20 @@ impl<'c> Type<'c> {
         unsafe { Self::from_raw(mlirNoneTypeGet(context.to_raw())) }
     }
 
+    downcast_functions!(
+        as_complex: ComplexType,
+        as_float: FloatType,
+        as_function: FunctionType,
+        as_integer: IntegerType,
+        as_mem_ref: MemRefType,
+        as_opaque: OpaqueType,
+        as_ranked_tensor: RankedTensorType,
+        as_shaped: ShapedType,
+        as_tuple: TupleType,
+        as_unranked_tensor: UnrankedTensorType,
+        as_vector: VectorType,
+    );
+
     /// Creates a vector type.
     pub fn vector(dimensions: &[u64], r#type: Self) -> Self {
         unsafe {
@@ -105,6 +150,21 @@ impl<'c> Type<'c> {
         }
     }
 
+    /// Prints a type to a writer.
+    pub fn print<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        let mut data: (_, io::Result<()>) = (writer, Ok(()));
+
+        unsafe {
+            mlirTypePrint(
+                self.raw,
+                Some(write_callback::<W>),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1.map_err(|error| Error::Print(error.to_string()))
+    }
+
     /// Creates a type from a raw object.
     ///
     /// # Safety
@@ -145,6 +205,14 @@ impl<'c> PartialEq for Type<'c> {
 
 impl<'c> Eq for Type<'c> {}
 
+// Types are uniqued in contexts. So equal types share the same storage
+// and hash identically.
+impl<'c> Hash for Type<'c> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.raw.ptr.hash(hasher);
+    }
+}
+
 impl<'c> Display for Type<'c> {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         let mut data = (formatter, Ok(()));
@@ -171,11 +239,17 @@ impl<'c> Debug for Type<'c> {
 
 from_subtypes!(
     Type,
+    ComplexType,
+    FloatType,
     FunctionType,
     IntegerType,
     MemRefType,
+    OpaqueType,
     RankedTensorType,
-    TupleType
+    ShapedType,
+    TupleType,
+    UnrankedTensorType,
+    VectorType
 );
 
 #[cfg(test)]
@@ -183,6 +257,7 @@ mod tests {
     use crate::test::create_test_context;
 
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn new() {
@@ -190,6 +265,26 @@ mod tests {
         Type::parse(&context, "f32");
     }
 
+    #[test]
+    fn try_parse() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::try_parse(&context, "f32"),
+            Ok(Type::float32(&context))
+        );
+    }
+
+    #[test]
+    fn try_parse_error() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            Type::try_parse(&context, "foo"),
+            Err(Error::ParseType(message)) if message.contains("expected non-function type")
+        ));
+    }
+
     #[test]
     fn integer() {
         let context = create_test_context();
@@ -210,6 +305,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn downcast() {
+        let context = create_test_context();
+        let r#type = Type::parse(&context, "tensor<2xi32>").unwrap();
+
+        assert_eq!(
+            r#type.as_ranked_tensor().map(|r#type| r#type.rank()),
+            Some(1)
+        );
+        assert!(r#type.as_shaped().is_some());
+        assert!(r#type.as_integer().is_none());
+        assert!(Type::index(&context).as_shaped().is_none());
+    }
+
     #[test]
     fn vector() {
         let context = create_test_context();
@@ -269,6 +378,25 @@ mod tests {
         assert_ne!(Type::index(&context), Type::float64(&context));
     }
 
+    #[test]
+    fn hash_map_key() {
+        let context = create_test_context();
+        let names = HashMap::from([
+            (Type::index(&context), "index"),
+            (Type::float64(&context), "f64"),
+        ]);
+
+        assert_eq!(
+            names.get(&Type::parse(&context, "index").unwrap()),
+            Some(&"index")
+        );
+        assert_eq!(
+            names.get(&Type::parse(&context, "f64").unwrap()),
+            Some(&"f64")
+        );
+        assert_eq!(names.get(&Type::float32(&context)), None);
+    }
+
     #[test]
     fn display() {
         let context = create_test_context();
@@ -282,4 +410,14 @@ mod tests {
 
         assert_eq!(format!("{:?}", Type::index(&context)), "Type(index)");
     }
+
+    #[test]
+    fn print() {
+        let context = create_test_context();
+        let mut buffer = vec![];
+
+        Type::index(&context).print(&mut buffer).unwrap();
+
+        assert_eq!(buffer, b"index");
+    }
 }
@@ -0,0 +1,81 @@
+use super::TypeLike;
+use crate::{ir::Type, Error};
+use mlir_sys::{mlirComplexTypeGet, mlirComplexTypeGetElementType, MlirType};
+
+/// A complex type.
+#[derive(Clone, Copy, Debug)]
+pub struct ComplexType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> ComplexType<'c> {
+    /// Creates a complex type.
+    pub fn new(r#type: Type<'c>) -> Self {
+        unsafe { Self::from_raw(mlirComplexTypeGet(r#type.to_raw())) }
+    }
+
+    /// Creates a complex type checking if an element type is a float or
+    /// integer type.
+    ///
+    /// Note that float element types are limited to the ones covered by
+    /// [`TypeLike::is_float`]. For example, `complex<f128>` is rejected here
+    /// although it is valid in MLIR.
+    pub fn checked(r#type: Type<'c>) -> Result<Self, Error> {
+        if r#type.is_integer() || r#type.is_float() {
+            Ok(Self::new(r#type))
+        } else {
+            Err(Error::InvalidType("complex"))
+        }
+    }
+
+    /// Gets an element type.
+    pub fn element(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirComplexTypeGetElementType(self.r#type.to_raw())) }
+    }
+}
+
+type_traits!(ComplexType, is_complex, "complex");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::r#type::IntegerType, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(ComplexType::new(Type::float64(&context))),
+            Type::parse(&context, "complex<f64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            ComplexType::checked(IntegerType::new(&context, 32).into()).map(Type::from),
+            Ok(Type::parse(&context, "complex<i32>").unwrap())
+        );
+    }
+
+    #[test]
+    fn checked_fail() {
+        let context = Context::new();
+
+        assert_eq!(
+            ComplexType::checked(Type::index(&context)).map(Type::from),
+            Err(Error::InvalidType("complex"))
+        );
+    }
+
+    #[test]
+    fn element() {
+        let context = Context::new();
+        let r#type = Type::float32(&context);
+
+        assert_eq!(ComplexType::new(r#type).element(), r#type);
+    }
+}
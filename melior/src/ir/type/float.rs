@@ -0,0 +1,114 @@
+use super::TypeLike;
+use crate::{ir::Type, Context, Error};
+use mlir_sys::MlirType;
+
+/// A float type.
+///
+/// The MLIR C API does not expose a bit width query of float types nor checks
+/// of `tf32`, `f80`, `f128` and the `*FNUZ` variants of `f8` types yet.
+/// Therefore, only the float types covered by [`TypeLike::is_float`] are
+/// supported here.
+#[derive(Clone, Copy, Debug)]
+pub struct FloatType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> FloatType<'c> {
+    /// Creates a bfloat16 type.
+    pub fn bfloat16(context: &'c Context) -> Self {
+        Self {
+            r#type: Type::bfloat16(context),
+        }
+    }
+
+    /// Creates a float16 type.
+    pub fn float16(context: &'c Context) -> Self {
+        Self {
+            r#type: Type::float16(context),
+        }
+    }
+
+    /// Creates a float32 type.
+    pub fn float32(context: &'c Context) -> Self {
+        Self {
+            r#type: Type::float32(context),
+        }
+    }
+
+    /// Creates a float64 type.
+    pub fn float64(context: &'c Context) -> Self {
+        Self {
+            r#type: Type::float64(context),
+        }
+    }
+
+    /// Gets a bit width.
+    pub fn width(&self) -> u32 {
+        if self.is_float8e4m3fn() || self.is_float8e5m2() {
+            8
+        } else if self.is_bf16() || self.is_f16() {
+            16
+        } else if self.is_f32() {
+            32
+        } else if self.is_f64() {
+            64
+        } else {
+            unreachable!("unknown float type: {}", self)
+        }
+    }
+}
+
+type_traits!(FloatType, is_float, "float");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bfloat16() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(FloatType::bfloat16(&context)),
+            Type::parse(&context, "bf16").unwrap()
+        );
+    }
+
+    #[test]
+    fn width() {
+        let context = Context::new();
+
+        assert_eq!(FloatType::bfloat16(&context).width(), 16);
+        assert_eq!(FloatType::float16(&context).width(), 16);
+        assert_eq!(FloatType::float32(&context).width(), 32);
+        assert_eq!(FloatType::float64(&context).width(), 64);
+        assert_eq!(
+            FloatType::try_from(Type::parse(&context, "f8E5M2").unwrap())
+                .unwrap()
+                .width(),
+            8
+        );
+    }
+
+    #[test]
+    fn predicates() {
+        let context = Context::new();
+        let r#type = FloatType::float32(&context);
+
+        assert!(r#type.is_f32());
+        assert!(!r#type.is_f16());
+        assert!(!r#type.is_bf16());
+        assert!(!r#type.is_f64());
+    }
+
+    #[test]
+    fn convert_from_type() {
+        let context = Context::new();
+
+        assert!(FloatType::try_from(Type::float64(&context)).is_ok());
+        assert_eq!(
+            FloatType::try_from(Type::index(&context)).map(Type::from),
+            Err(Error::TypeExpected("float", "index".into()))
+        );
+    }
+}
@@ -63,6 +63,26 @@ impl<'c> FunctionType<'c> {
         }
     }
 
+    /// Gets inputs.
+    pub fn inputs(&self) -> impl Iterator<Item = Type<'c>> + '_ {
+        (0..self.input_count()).map(|index| unsafe {
+            Type::from_raw(mlirFunctionTypeGetInput(
+                self.r#type.to_raw(),
+                index as isize,
+            ))
+        })
+    }
+
+    /// Gets results.
+    pub fn results(&self) -> impl Iterator<Item = Type<'c>> + '_ {
+        (0..self.result_count()).map(|index| unsafe {
+            Type::from_raw(mlirFunctionTypeGetResult(
+                self.r#type.to_raw(),
+                index as isize,
+            ))
+        })
+    }
+
     /// Gets a number of inputs.
     pub fn input_count(&self) -> usize {
         unsafe { mlirFunctionTypeGetNumInputs(self.r#type.to_raw()) as usize }
@@ -178,4 +198,32 @@ mod tests {
             1
         );
     }
+
+    #[test]
+    fn inputs() {
+        let context = Context::new();
+        let index = Type::index(&context);
+        let float = Type::float64(&context);
+
+        assert_eq!(
+            FunctionType::new(&context, &[index, float], &[])
+                .inputs()
+                .collect::<Vec<_>>(),
+            vec![index, float]
+        );
+    }
+
+    #[test]
+    fn results() {
+        let context = Context::new();
+        let index = Type::index(&context);
+        let float = Type::float64(&context);
+
+        assert_eq!(
+            FunctionType::new(&context, &[], &[float, index])
+                .results()
+                .collect::<Vec<_>>(),
+            vec![float, index]
+        );
+    }
 }
@@ -72,6 +72,14 @@ impl Hash for TypeId<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        ir::{
+            r#type::{IntegerType, TypeLike},
+            Type,
+        },
+        Context,
+    };
+    use std::collections::HashMap;
 
     #[test]
     fn create_from_reference() {
@@ -80,6 +88,40 @@ mod tests {
         TypeId::create(&VALUE);
     }
 
+    #[test]
+    fn equal() {
+        let context = Context::new();
+
+        assert_eq!(Type::float32(&context).id(), Type::float32(&context).id());
+        assert_eq!(
+            IntegerType::new(&context, 8).id(),
+            IntegerType::new(&context, 64).id()
+        );
+    }
+
+    #[test]
+    fn not_equal() {
+        let context = Context::new();
+
+        assert_ne!(Type::index(&context).id(), Type::float32(&context).id());
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let context = Context::new();
+        let names = HashMap::from([
+            (Type::index(&context).id(), "index"),
+            (IntegerType::new(&context, 1).id(), "integer"),
+        ]);
+
+        assert_eq!(
+            names.get(&IntegerType::new(&context, 32).id()),
+            Some(&"integer")
+        );
+        assert_eq!(names.get(&Type::index(&context).id()), Some(&"index"));
+        assert_eq!(names.get(&Type::float64(&context).id()), None);
+    }
+
     #[test]
     #[should_panic]
     fn reject_invalid_alignment() {
@@ -37,20 +37,21 @@ impl<'c> MemRefType<'c> {
     pub fn checked(
         location: Location<'c>,
         r#type: Type<'c>,
-        dimensions: &[u64],
-        layout: Attribute<'c>,
-        memory_space: Attribute<'c>,
-    ) -> Option<Self> {
+        dimensions: &[i64],
+        layout: Option<Attribute<'c>>,
+        memory_space: Option<Attribute<'c>>,
+    ) -> Result<Self, Error> {
         unsafe {
             Self::from_option_raw(mlirMemRefTypeGetChecked(
                 location.to_raw(),
                 r#type.to_raw(),
                 dimensions.len() as isize,
-                dimensions.as_ptr() as *const i64,
-                layout.to_raw(),
-                memory_space.to_raw(),
+                dimensions.as_ptr(),
+                layout.unwrap_or_else(|| Attribute::null()).to_raw(),
+                memory_space.unwrap_or_else(|| Attribute::null()).to_raw(),
             ))
         }
+        .ok_or(Error::InvalidType("mem ref"))
     }
 
     /// Gets a layout.
@@ -111,6 +112,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn new_with_layout_and_memory_space() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(MemRefType::new(
+                Type::float64(&context),
+                &[42, 42],
+                Some(Attribute::parse(&context, "affine_map<(d0, d1) -> (d1, d0)>").unwrap()),
+                Some(Attribute::parse(&context, "1").unwrap()),
+            )),
+            Type::parse(
+                &context,
+                "memref<42x42xf64, affine_map<(d0, d1) -> (d1, d0)>, 1>"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::checked(
+                Location::unknown(&context),
+                Type::float64(&context),
+                &[42],
+                None,
+                None,
+            )
+            .map(Type::from),
+            Ok(Type::parse(&context, "memref<42xf64>").unwrap())
+        );
+    }
+
+    #[test]
+    fn checked_invalid() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::checked(
+                Location::unknown(&context),
+                Type::float64(&context),
+                &[-42],
+                None,
+                None,
+            )
+            .map(Type::from),
+            Err(Error::InvalidType("mem ref"))
+        );
+    }
+
     #[test]
     fn layout() {
         let context = Context::new();
@@ -0,0 +1,74 @@
+use super::TypeLike;
+use crate::{ir::Type, Context, Error, StringRef};
+use mlir_sys::{
+    mlirOpaqueTypeGet, mlirOpaqueTypeGetData, mlirOpaqueTypeGetDialectNamespace, MlirType,
+};
+
+/// An opaque type.
+#[derive(Clone, Copy, Debug)]
+pub struct OpaqueType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> OpaqueType<'c> {
+    /// Creates an opaque type.
+    pub fn new(context: &'c Context, dialect_namespace: &str, data: &str) -> Self {
+        unsafe {
+            Self::from_raw(mlirOpaqueTypeGet(
+                context.to_raw(),
+                StringRef::new(dialect_namespace).to_raw(),
+                StringRef::new(data).to_raw(),
+            ))
+        }
+    }
+
+    /// Gets a dialect namespace.
+    pub fn dialect_namespace(&self) -> StringRef<'c> {
+        unsafe { StringRef::from_raw(mlirOpaqueTypeGetDialectNamespace(self.r#type.to_raw())) }
+    }
+
+    /// Gets data.
+    pub fn data(&self) -> StringRef<'c> {
+        unsafe { StringRef::from_raw(mlirOpaqueTypeGetData(self.r#type.to_raw())) }
+    }
+}
+
+type_traits!(OpaqueType, is_opaque, "opaque");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+        context.set_allow_unregistered_dialects(true);
+
+        assert_eq!(
+            Type::from(OpaqueType::new(&context, "foo", "bar")),
+            Type::parse(&context, "!foo.bar").unwrap()
+        );
+    }
+
+    #[test]
+    fn dialect_namespace() {
+        let context = Context::new();
+
+        assert_eq!(
+            OpaqueType::new(&context, "foo", "bar")
+                .dialect_namespace()
+                .as_str(),
+            Ok("foo")
+        );
+    }
+
+    #[test]
+    fn data() {
+        let context = Context::new();
+
+        assert_eq!(
+            OpaqueType::new(&context, "foo", "bar").data().as_str(),
+            Ok("bar")
+        );
+    }
+}
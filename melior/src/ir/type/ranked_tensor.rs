@@ -66,7 +66,7 @@ type_traits!(RankedTensorType, is_ranked_tensor, "tensor");
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Context;
+    use crate::{ir::r#type::DYNAMIC, Context};
 
     #[test]
     fn new() {
@@ -78,6 +78,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shape() {
+        let context = Context::new();
+        let r#type = RankedTensorType::new(&[DYNAMIC as u64, 42], Type::float64(&context), None);
+
+        assert_eq!(r#type.rank(), 2);
+        assert_eq!(r#type.element(), Type::float64(&context));
+        assert!(r#type.is_dynamic_dim(0));
+        assert!(!r#type.is_dynamic_dim(1));
+        assert_eq!(r#type.dim_size(1), Ok(42));
+        assert_eq!(
+            Type::from(r#type),
+            Type::parse(&context, "tensor<?x42xf64>").unwrap()
+        );
+    }
+
     #[test]
     fn encoding() {
         let context = Context::new();
@@ -0,0 +1,50 @@
+use super::{shaped_type_like::ShapedTypeLike, TypeLike};
+use crate::{ir::Type, Error};
+use mlir_sys::MlirType;
+
+/// A shaped type.
+///
+/// It is any of tensor, memref, and vector types. Use it to write code generic
+/// over shaped types.
+#[derive(Clone, Copy, Debug)]
+pub struct ShapedType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> ShapedTypeLike<'c> for ShapedType<'c> {}
+
+type_traits!(ShapedType, is_shaped, "shaped");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::r#type::{MemRefType, RankedTensorType, UnrankedTensorType, VectorType},
+        Context,
+    };
+
+    #[test]
+    fn convert_from_type() {
+        let context = Context::new();
+        let element_type = Type::index(&context);
+
+        let types: [Type; 4] = [
+            RankedTensorType::new(&[2], element_type, None).into(),
+            UnrankedTensorType::new(element_type).into(),
+            MemRefType::new(element_type, &[2], None, None).into(),
+            VectorType::new(&[2], element_type).into(),
+        ];
+
+        for r#type in types {
+            assert_eq!(
+                ShapedType::try_from(r#type).unwrap().element(),
+                element_type
+            );
+        }
+
+        assert_eq!(
+            ShapedType::try_from(element_type).map(Type::from),
+            Err(Error::TypeExpected("shaped", "index".into()))
+        );
+    }
+}
@@ -3,9 +3,18 @@ use crate::Error;
 use super::{Type, TypeLike};
 use mlir_sys::{
     mlirShapedTypeGetDimSize, mlirShapedTypeGetElementType, mlirShapedTypeGetRank,
-    mlirShapedTypeHasRank,
+    mlirShapedTypeHasRank, mlirShapedTypeHasStaticShape, mlirShapedTypeIsDynamicDim,
+    mlirShapedTypeIsDynamicSize,
 };
 
+/// A size of dynamic dimensions in shaped types.
+pub const DYNAMIC: i64 = i64::MIN;
+
+/// Checks if a dimension size is dynamic.
+pub fn is_dynamic_size(size: i64) -> bool {
+    unsafe { mlirShapedTypeIsDynamicSize(size) }
+}
+
 /// Trait for shaped types.
 pub trait ShapedTypeLike<'c>: TypeLike<'c> {
     /// Gets a element type.
@@ -31,17 +40,49 @@ pub trait ShapedTypeLike<'c>: TypeLike<'c> {
         }
     }
 
+    /// Checks if a dimension is dynamic.
+    fn is_dynamic_dim(&self, index: usize) -> bool {
+        index < self.rank() && unsafe { mlirShapedTypeIsDynamicDim(self.to_raw(), index as isize) }
+    }
+
     /// Checks if a type has a rank.
     fn has_rank(&self) -> bool {
         unsafe { mlirShapedTypeHasRank(self.to_raw()) }
     }
+
+    /// Checks if a type has a static shape.
+    fn has_static_shape(&self) -> bool {
+        unsafe { mlirShapedTypeHasStaticShape(self.to_raw()) }
+    }
+
+    /// Gets a number of elements.
+    ///
+    /// It returns an error if a type does not have a static shape.
+    fn element_count(&self) -> Result<usize, Error> {
+        if self.has_static_shape() {
+            Ok((0..self.rank())
+                .map(
+                    |index| unsafe { mlirShapedTypeGetDimSize(self.to_raw(), index as isize) }
+                        as usize,
+                )
+                .product())
+        } else {
+            Err(Error::TypeExpected(
+                "statically shaped",
+                unsafe { Type::from_raw(self.to_raw()) }.to_string(),
+            ))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        ir::{r#type::MemRefType, Type},
+        ir::{
+            r#type::{MemRefType, UnrankedTensorType},
+            Type,
+        },
         Context,
     };
 
@@ -106,6 +147,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_dynamic_dim() {
+        let context = Context::new();
+        let r#type = MemRefType::new(Type::index(&context), &[DYNAMIC, 42], None, None);
+
+        assert!(r#type.is_dynamic_dim(0));
+        assert!(!r#type.is_dynamic_dim(1));
+        assert!(!r#type.is_dynamic_dim(2));
+    }
+
+    #[test]
+    fn element_count() {
+        let context = Context::new();
+        let element_type = Type::index(&context);
+
+        assert_eq!(
+            MemRefType::new(element_type, &[], None, None).element_count(),
+            Ok(1)
+        );
+        assert_eq!(
+            MemRefType::new(element_type, &[2, 3], None, None).element_count(),
+            Ok(6)
+        );
+        assert_eq!(
+            MemRefType::new(element_type, &[DYNAMIC, 3], None, None).element_count(),
+            Err(Error::TypeExpected(
+                "statically shaped",
+                "memref<?x3xindex>".into()
+            ))
+        );
+        assert_eq!(
+            UnrankedTensorType::new(element_type).element_count(),
+            Err(Error::TypeExpected(
+                "statically shaped",
+                "tensor<*xindex>".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn has_static_shape() {
+        let context = Context::new();
+        let element_type = Type::index(&context);
+
+        assert!(MemRefType::new(element_type, &[42], None, None).has_static_shape());
+        assert!(!MemRefType::new(element_type, &[DYNAMIC], None, None).has_static_shape());
+        assert!(!UnrankedTensorType::new(element_type).has_static_shape());
+    }
+
+    #[test]
+    fn dynamic_size() {
+        assert!(is_dynamic_size(DYNAMIC));
+        assert!(!is_dynamic_size(42));
+    }
+
     #[test]
     fn has_rank() {
         let context = Context::new();
@@ -21,7 +21,7 @@ impl<'c> TupleType<'c> {
     }
 
     /// Gets a field at a position.
-    pub fn r#type(&self, index: usize) -> Result<Type, Error> {
+    pub fn r#type(&self, index: usize) -> Result<Type<'c>, Error> {
         if index < self.type_count() {
             unsafe {
                 Ok(Type::from_raw(mlirTupleTypeGetType(
@@ -38,6 +38,13 @@ impl<'c> TupleType<'c> {
         }
     }
 
+    /// Gets fields.
+    pub fn types(&self) -> impl Iterator<Item = Type<'c>> + '_ {
+        (0..self.type_count()).map(|index| unsafe {
+            Type::from_raw(mlirTupleTypeGetType(self.r#type.to_raw(), index as isize))
+        })
+    }
+
     /// Gets a number of fields.
     pub fn type_count(&self) -> usize {
         unsafe { mlirTupleTypeGetNumTypes(self.r#type.to_raw()) as usize }
@@ -112,4 +119,26 @@ mod tests {
     fn type_count() {
         assert_eq!(TupleType::new(&Context::new(), &[]).type_count(), 0);
     }
+
+    #[test]
+    fn types() {
+        let context = Context::new();
+        let index_type = Type::index(&context);
+        let float64_type = Type::float64(&context);
+
+        assert_eq!(
+            TupleType::new(&context, &[index_type, float64_type])
+                .types()
+                .collect::<Vec<_>>(),
+            vec![index_type, float64_type]
+        );
+    }
+
+    #[test]
+    fn convert_from_type() {
+        let context = Context::new();
+        let r#type = Type::parse(&context, "tuple<index>").unwrap();
+
+        assert_eq!(Type::from(TupleType::try_from(r#type).unwrap()), r#type);
+    }
 }
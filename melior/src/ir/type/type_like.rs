@@ -22,6 +22,21 @@ pub trait TypeLike<'c> {
         unsafe { mlirTypeDump(self.to_raw()) }
     }
 
+    /// Returns `true` if a type is a `bf16`, `f16`, `f32`, `f64`, `f8E4M3FN` or
+    /// `f8E5M2` type.
+    ///
+    /// Other builtin float types, such as `tf32`, `f80`, `f128` and the
+    /// `*FNUZ` variants of `f8`, are not covered as the MLIR C API cannot check
+    /// them yet.
+    fn is_float(&self) -> bool {
+        self.is_bf16()
+            || self.is_f16()
+            || self.is_f32()
+            || self.is_f64()
+            || self.is_float8e4m3fn()
+            || self.is_float8e5m2()
+    }
+
     melior_macro::type_check_functions!(
         mlirTypeIsAAnyQuantizedType,
         mlirTypeIsABF16,
@@ -103,6 +118,15 @@ mod tests {
         assert!(FunctionType::new(&context, &[], &[]).is_function());
     }
 
+    #[test]
+    fn is_float() {
+        let context = Context::new();
+
+        assert!(Type::bfloat16(&context).is_float());
+        assert!(Type::float64(&context).is_float());
+        assert!(!Type::index(&context).is_float());
+    }
+
     #[test]
     fn is_function() {
         let context = Context::new();
@@ -0,0 +1,190 @@
+use super::{shaped_type_like::ShapedTypeLike, TypeLike};
+use crate::{
+    ir::{Location, Type},
+    Error,
+};
+use mlir_sys::{
+    mlirVectorTypeGet, mlirVectorTypeGetChecked, mlirVectorTypeGetScalable,
+    mlirVectorTypeIsDimScalable, mlirVectorTypeIsScalable, MlirType,
+};
+
+/// A vector type.
+#[derive(Clone, Copy, Debug)]
+pub struct VectorType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> VectorType<'c> {
+    /// Creates a vector type.
+    pub fn new(dimensions: &[u64], r#type: Type<'c>) -> Self {
+        unsafe {
+            Self::from_raw(mlirVectorTypeGet(
+                dimensions.len() as _,
+                dimensions.as_ptr() as *const _,
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates a vector type with diagnostics.
+    pub fn checked(
+        location: Location<'c>,
+        dimensions: &[u64],
+        r#type: Type<'c>,
+    ) -> Result<Self, Error> {
+        unsafe {
+            Self::from_option_raw(mlirVectorTypeGetChecked(
+                location.to_raw(),
+                dimensions.len() as _,
+                dimensions.as_ptr() as *const _,
+                r#type.to_raw(),
+            ))
+        }
+        .ok_or(Error::InvalidType("vector"))
+    }
+
+    /// Creates a vector type with scalable dimensions.
+    pub fn scalable(
+        dimensions: &[u64],
+        scalable_dimensions: &[bool],
+        r#type: Type<'c>,
+    ) -> Result<Self, Error> {
+        if dimensions.len() != scalable_dimensions.len() {
+            return Err(Error::ArgumentLengthMismatch {
+                name: "scalable_dimensions",
+                expected: dimensions.len(),
+                actual: scalable_dimensions.len(),
+            });
+        }
+
+        Ok(unsafe {
+            Self::from_raw(mlirVectorTypeGetScalable(
+                dimensions.len() as _,
+                dimensions.as_ptr() as *const _,
+                scalable_dimensions.as_ptr(),
+                r#type.to_raw(),
+            ))
+        })
+    }
+
+    /// Returns `true` if a vector type has any scalable dimensions.
+    pub fn is_scalable(&self) -> bool {
+        unsafe { mlirVectorTypeIsScalable(self.r#type.to_raw()) }
+    }
+
+    /// Returns `true` if a dimension at a position is scalable.
+    pub fn is_dim_scalable(&self, index: usize) -> Result<bool, Error> {
+        if index < self.rank() {
+            Ok(unsafe { mlirVectorTypeIsDimScalable(self.r#type.to_raw(), index as _) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "vector dimension",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+}
+
+impl<'c> ShapedTypeLike<'c> for VectorType<'c> {}
+
+type_traits!(VectorType, is_vector, "vector");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(VectorType::new(&[42], Type::float64(&context))),
+            Type::parse(&context, "vector<42xf64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            VectorType::checked(Location::unknown(&context), &[42], Type::float64(&context))
+                .map(Type::from),
+            Ok(Type::parse(&context, "vector<42xf64>").unwrap())
+        );
+    }
+
+    #[test]
+    fn checked_fail() {
+        let context = Context::new();
+
+        assert_eq!(
+            VectorType::checked(Location::unknown(&context), &[0], Type::index(&context))
+                .map(Type::from),
+            Err(Error::InvalidType("vector"))
+        );
+    }
+
+    #[test]
+    fn scalable() {
+        let context = Context::new();
+
+        assert_eq!(
+            VectorType::scalable(&[2, 4], &[false, true], Type::float32(&context)).map(Type::from),
+            Ok(Type::parse(&context, "vector<2x[4]xf32>").unwrap())
+        );
+    }
+
+    #[test]
+    fn scalable_with_dimension_count_mismatch() {
+        let context = Context::new();
+
+        assert_eq!(
+            VectorType::scalable(&[2, 4], &[true], Type::float32(&context)).map(Type::from),
+            Err(Error::ArgumentLengthMismatch {
+                name: "scalable_dimensions",
+                expected: 2,
+                actual: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn is_scalable() {
+        let context = Context::new();
+        let r#type = Type::float32(&context);
+
+        assert!(!VectorType::new(&[4], r#type).is_scalable());
+        assert!(VectorType::scalable(&[4], &[true], r#type)
+            .unwrap()
+            .is_scalable());
+    }
+
+    #[test]
+    fn is_dim_scalable() {
+        let context = Context::new();
+        let vector =
+            VectorType::scalable(&[2, 4], &[false, true], Type::float32(&context)).unwrap();
+
+        assert_eq!(vector.is_dim_scalable(0), Ok(false));
+        assert_eq!(vector.is_dim_scalable(1), Ok(true));
+        assert_eq!(
+            vector.is_dim_scalable(2),
+            Err(Error::PositionOutOfBounds {
+                name: "vector dimension",
+                value: vector.to_string(),
+                index: 2,
+            })
+        );
+    }
+}
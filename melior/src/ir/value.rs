@@ -1,9 +1,13 @@
 mod value_like;
 
 pub use self::value_like::ValueLike;
-use super::{block::BlockArgument, operation::OperationResult, Type};
+use super::{
+    block::BlockArgument,
+    operation::{OperationOperand, OperationResult},
+    Type,
+};
 use crate::{utility::print_callback, Context};
-use mlir_sys::{mlirValueEqual, mlirValuePrint, MlirValue};
+use mlir_sys::{mlirValueEqual, mlirValueGetFirstUse, mlirValuePrint, MlirValue};
 use std::{
     ffi::c_void,
     fmt::{self, Debug, Display, Formatter},
@@ -33,6 +37,29 @@ impl<'c, 'a> Value<'c, 'a> {
             _parent: Default::default(),
         }
     }
+
+    /// Converts a value into a block argument if it is one.
+    pub fn as_block_argument(&self) -> Option<BlockArgument<'c, 'a>> {
+        BlockArgument::try_from(*self).ok()
+    }
+
+    /// Converts a value into an operation result if it is one.
+    pub fn as_operation_result(&self) -> Option<OperationResult<'c, 'a>> {
+        OperationResult::try_from(*self).ok()
+    }
+
+    /// Gets uses of a value.
+    pub fn uses(&self) -> impl Iterator<Item = OperationOperand<'c, 'a>> {
+        std::iter::successors(
+            unsafe { OperationOperand::from_option_raw(mlirValueGetFirstUse(self.raw)) },
+            |operand| operand.next_use(),
+        )
+    }
+
+    /// Gets a number of uses of a value.
+    pub fn use_count(&self) -> usize {
+        self.uses().count()
+    }
 }
 
 impl<'c, 'a> ValueLike<'c> for Value<'c, 'a> {
@@ -129,6 +156,102 @@ mod tests {
         assert!(block.argument(0).unwrap().is_block_argument());
     }
 
+    #[test]
+    fn as_block_argument() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument = Value::from(block.argument(0).unwrap());
+
+        assert_eq!(
+            argument
+                .as_block_argument()
+                .map(|argument| argument.argument_number()),
+            Some(0)
+        );
+        assert!(argument.as_operation_result().is_none());
+    }
+
+    #[test]
+    fn as_operation_result() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let r#type = Type::index(&context);
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_results(&[r#type, r#type])
+            .build()
+            .unwrap();
+        let result = Value::from(operation.result(1).unwrap());
+
+        assert_eq!(
+            result
+                .as_operation_result()
+                .map(|result| result.result_number()),
+            Some(1)
+        );
+        assert!(result.as_block_argument().is_none());
+    }
+
+    #[test]
+    fn replace_all_uses_with() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let first = block.argument(0).unwrap();
+        let second = block.argument(1).unwrap();
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[first.into(), first.into()])
+                .build()
+                .unwrap(),
+        );
+
+        first.replace_all_uses_with(second.into());
+
+        assert_eq!(
+            operation.operands().collect::<Vec<_>>(),
+            vec![Value::from(second), Value::from(second)]
+        );
+    }
+
+    #[test]
+    fn uses() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument = Value::from(block.argument(0).unwrap());
+
+        assert_eq!(argument.use_count(), 0);
+
+        block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument, argument])
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(argument.use_count(), 2);
+
+        let mut operand_numbers = argument
+            .uses()
+            .map(|operand| operand.operand_number())
+            .collect::<Vec<_>>();
+        operand_numbers.sort();
+
+        assert_eq!(operand_numbers, vec![0, 1]);
+    }
+
     #[test]
     fn dump() {
         let context = create_test_context();
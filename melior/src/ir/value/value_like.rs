@@ -1,6 +1,7 @@
-use super::Type;
+use super::{Type, Value};
 use mlir_sys::{
-    mlirValueDump, mlirValueGetType, mlirValueIsABlockArgument, mlirValueIsAOpResult, MlirValue,
+    mlirValueDump, mlirValueGetType, mlirValueIsABlockArgument, mlirValueIsAOpResult,
+    mlirValueReplaceAllUsesOfWith, MlirValue,
 };
 
 /// Trait for value-like types.
@@ -23,6 +24,11 @@ pub trait ValueLike<'c> {
         unsafe { mlirValueIsAOpResult(self.to_raw()) }
     }
 
+    /// Replaces all uses of a value with another value.
+    fn replace_all_uses_with(&self, value: Value<'c, '_>) {
+        unsafe { mlirValueReplaceAllUsesOfWith(self.to_raw(), value.to_raw()) }
+    }
+
     /// Dumps a value.
     fn dump(&self) {
         unsafe { mlirValueDump(self.to_raw()) }
@@ -15,6 +15,7 @@ pub mod pass;
 mod string_ref;
 #[cfg(test)]
 mod test;
+mod thread_pool;
 pub mod utility;
 
 pub use self::{
@@ -22,6 +23,7 @@ pub use self::{
     error::Error,
     execution_engine::ExecutionEngine,
     string_ref::StringRef,
+    thread_pool::ThreadPool,
 };
 
 pub use melior_macro::dialect;
@@ -23,3 +23,14 @@ macro_rules! from_borrowed_subtypes {
         from_borrowed_subtypes!($type, $($names,)*);
     };
 }
+
+macro_rules! downcast_functions {
+    ($($function:ident: $name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Converts into [`", stringify!($name), "`] if possible.")]
+            pub fn $function(&self) -> Option<$name<'c>> {
+                $name::try_from(*self).ok()
+            }
+        )*
+    };
+}
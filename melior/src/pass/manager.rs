@@ -1,7 +1,7 @@
 use super::OperationPassManager;
 use crate::{
     context::Context, ir::Module, logical_result::LogicalResult, pass::Pass, string_ref::StringRef,
-    Error,
+    utility::collect_diagnostics, Error,
 };
 use mlir_sys::{
     mlirPassManagerAddOwnedPass, mlirPassManagerCreate, mlirPassManagerDestroy,
@@ -47,20 +47,29 @@ impl<'c> PassManager<'c> {
     }
 
     /// Enables IR printing.
+    ///
+    /// IR is printed to stderr with the default options of MLIR. Note that the
+    /// MLIR C API does not expose IR printing configuration yet.
     pub fn enable_ir_printing(&self) {
         unsafe { mlirPassManagerEnableIRPrinting(self.raw) }
     }
 
     /// Runs passes added to a pass manager against a module.
+    ///
+    /// On failure, an error contains diagnostics emitted by the failed passes
+    /// or verification. Note that it does not tell which pass failed because
+    /// the MLIR C API does not report it.
     pub fn run(&self, module: &mut Module) -> Result<(), Error> {
-        let result = LogicalResult::from_raw(unsafe {
-            mlirPassManagerRunOnOp(self.raw, module.as_operation().to_raw())
+        let (result, diagnostics) = collect_diagnostics(&module.context(), || {
+            LogicalResult::from_raw(unsafe {
+                mlirPassManagerRunOnOp(self.raw, module.as_operation().to_raw())
+            })
         });
 
         if result.is_success() {
             Ok(())
         } else {
-            Err(Error::RunPass)
+            Err(Error::RunPass(diagnostics.join("\n")))
         }
     }
 
@@ -80,7 +89,7 @@ impl<'c> Drop for PassManager<'c> {
 mod tests {
     use super::*;
     use crate::{
-        ir::{Location, Module},
+        ir::{operation::OperationBuilder, Location, Module},
         pass::{self, transform::register_print_op_stats},
         test::create_test_context,
         utility::parse_pass_pipeline,
@@ -128,6 +137,30 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn run_with_verification_failure() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let mut module = Module::new(location);
+
+        module.body().append_operation(
+            OperationBuilder::new("func.return", location)
+                .build()
+                .unwrap(),
+        );
+
+        let manager = PassManager::new(&context);
+        manager.add_pass(pass::transform::create_strip_debug_info());
+
+        let error = manager.run(&mut module).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            Error::RunPass(message) if message.contains("expects parent op 'func.func'")
+        ));
+        assert!(error.to_string().starts_with("failed to run pass:\n"));
+    }
+
     #[test]
     fn run_on_function() {
         let context = create_test_context();
@@ -238,4 +271,36 @@ mod tests {
             func.func(print-op-stats{json=false}))"
         );
     }
+
+    #[test]
+    fn add_pipeline() {
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+
+        register_print_op_stats();
+
+        let function_manager = manager.nested_under("func.func");
+
+        assert_eq!(
+            function_manager.add_pipeline("print-op-stats{json=false}"),
+            Ok(())
+        );
+        assert_eq!(
+            function_manager.to_string(),
+            "func.func(print-op-stats{json=false})"
+        );
+    }
+
+    #[test]
+    fn add_pipeline_error() {
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+
+        assert!(matches!(
+            manager
+                .as_operation_pass_manager()
+                .add_pipeline("unknown-pass"),
+            Err(Error::ParsePassPipeline(_))
+        ));
+    }
 }
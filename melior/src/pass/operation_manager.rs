@@ -1,8 +1,11 @@
 use super::PassManager;
-use crate::{pass::Pass, string_ref::StringRef};
+use crate::{
+    logical_result::LogicalResult, pass::Pass, string_ref::StringRef, utility::handle_parse_error,
+    Error,
+};
 use mlir_sys::{
-    mlirOpPassManagerAddOwnedPass, mlirOpPassManagerGetNestedUnder, mlirPrintPassPipeline,
-    MlirOpPassManager, MlirStringRef,
+    mlirOpPassManagerAddOwnedPass, mlirOpPassManagerAddPipeline, mlirOpPassManagerGetNestedUnder,
+    mlirPrintPassPipeline, MlirOpPassManager, MlirStringRef,
 };
 use std::{
     ffi::c_void,
@@ -31,6 +34,28 @@ impl<'c, 'a> OperationPassManager<'c, 'a> {
         unsafe { mlirOpPassManagerAddOwnedPass(self.raw, pass.to_raw()) }
     }
 
+    /// Adds passes in a textual pass pipeline.
+    pub fn add_pipeline(&self, pipeline: &str) -> Result<(), Error> {
+        let mut error_message = None;
+
+        let result = LogicalResult::from_raw(unsafe {
+            mlirOpPassManagerAddPipeline(
+                self.raw,
+                StringRef::new(pipeline).to_raw(),
+                Some(handle_parse_error),
+                &mut error_message as *mut _ as *mut _,
+            )
+        });
+
+        if result.is_success() {
+            Ok(())
+        } else {
+            Err(Error::ParsePassPipeline(error_message.unwrap_or_else(
+                || "failed to parse error message in UTF-8".into(),
+            )))
+        }
+    }
+
     /// Converts an operation pass manager into a raw object.
     pub const fn to_raw(self) -> MlirOpPassManager {
         self.raw
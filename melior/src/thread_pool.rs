@@ -0,0 +1,43 @@
+use mlir_sys::{mlirLlvmThreadPoolCreate, mlirLlvmThreadPoolDestroy, MlirLlvmThreadPool};
+
+/// A thread pool which can be shared by multiple contexts.
+#[derive(Debug)]
+pub struct ThreadPool {
+    raw: MlirLlvmThreadPool,
+}
+
+impl ThreadPool {
+    /// Creates a thread pool.
+    pub fn new() -> Self {
+        Self {
+            raw: unsafe { mlirLlvmThreadPoolCreate() },
+        }
+    }
+
+    /// Converts a thread pool into a raw object.
+    pub const fn to_raw(&self) -> MlirLlvmThreadPool {
+        self.raw
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        unsafe { mlirLlvmThreadPoolDestroy(self.raw) }
+    }
+}
+
+impl Default for ThreadPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        ThreadPool::new();
+    }
+}
@@ -11,6 +11,8 @@ use mlir_sys::{
 use std::{
     ffi::c_void,
     fmt::{self, Formatter},
+    io::{self, Write},
+    slice,
     sync::Once,
 };
 
@@ -20,6 +22,9 @@ pub fn register_all_dialects(registry: &DialectRegistry) {
 }
 
 /// Register all translations from other dialects to the `llvm` dialect.
+///
+/// This is required before translating modules into LLVM IR, such as on
+/// creation of an [`ExecutionEngine`](crate::ExecutionEngine).
 pub fn register_all_llvm_translations(context: &Context) {
     unsafe { mlirRegisterAllLLVMTranslations(context.to_raw()) }
 }
@@ -54,7 +59,27 @@ pub fn parse_pass_pipeline(manager: pass::OperationPassManager, source: &str) ->
     }
 }
 
-unsafe extern "C" fn handle_parse_error(raw_string: MlirStringRef, data: *mut c_void) {
+/// Runs a function collecting diagnostics emitted in a context.
+///
+/// The diagnostics are still propagated to handlers attached before.
+pub(crate) fn collect_diagnostics<T>(
+    context: &Context,
+    function: impl FnOnce() -> T,
+) -> (T, Vec<String>) {
+    let mut diagnostics = vec![];
+    let id = context.attach_diagnostic_handler(|diagnostic| {
+        diagnostics.push(diagnostic.to_string());
+        false
+    });
+
+    let value = function();
+
+    context.detach_diagnostic_handler(id);
+
+    (value, diagnostics)
+}
+
+pub(crate) unsafe extern "C" fn handle_parse_error(raw_string: MlirStringRef, data: *mut c_void) {
     let string = StringRef::from_raw(raw_string);
     let data = &mut *(data as *mut Option<String>);
 
@@ -97,6 +122,19 @@ pub(crate) unsafe extern "C" fn print_string_callback(string: MlirStringRef, dat
     })();
 }
 
+pub(crate) unsafe extern "C" fn write_callback<W: Write>(string: MlirStringRef, data: *mut c_void) {
+    let (writer, result) = &mut *(data as *mut (&mut W, io::Result<()>));
+
+    if result.is_err() {
+        return;
+    }
+
+    *result = writer.write_all(slice::from_raw_parts(
+        string.data as *const u8,
+        string.length,
+    ));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;